@@ -2,4 +2,6 @@ extern crate iso4217;
 
 fn main() {
     let currencies = iso4217::all();
+
+    println!("{:?}", currencies);
 }
@@ -0,0 +1,93 @@
+//! Compares the indexed lookups against linear scans over every currency,
+//! and bulk validation of a column of codes against calling `alpha3` on
+//! each row.
+//!
+//! Run with `cargo run --release --example bench`.
+
+extern crate iso4217;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 2_000;
+
+fn time<F: FnMut(&'static iso4217::CurrencyCode) -> usize>(mut lookup: F) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..ROUNDS {
+        for currency in iso4217::all() {
+            black_box(lookup(black_box(currency)));
+        }
+    }
+
+    start.elapsed() / (ROUNDS * iso4217::all().len() as u32)
+}
+
+fn report(label: &str, scan: Duration, indexed: Duration) {
+    println!(
+        "{:<8} scan {:>8?}  indexed {:>8?}  {:>6.1}x",
+        label,
+        scan,
+        indexed,
+        scan.as_secs_f64() / indexed.as_secs_f64(),
+    );
+}
+
+/// Times validating the column of a million rows, keeping the fastest of a
+/// few runs.
+fn column<T, F: Fn() -> T>(validate: F) -> Duration {
+    (0..10)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(validate());
+
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report_bulk(label: &str, per_row: Duration, bulk: Duration) {
+    println!(
+        "{:<8} per row {:>8?}  bulk {:>8?}  {:>6.1}x  (1M rows)",
+        label,
+        per_row,
+        bulk,
+        per_row.as_secs_f64() / bulk.as_secs_f64(),
+    );
+}
+
+fn main() {
+    let all = iso4217::all();
+
+    report(
+        "alpha3",
+        time(|c| all.iter().find(|x| x.alpha3 == c.alpha3).is_some() as usize),
+        time(|c| iso4217::alpha3(c.alpha3).is_some() as usize),
+    );
+    report(
+        "num",
+        time(|c| all.iter().find(|x| x.num == c.num).is_some() as usize),
+        time(|c| iso4217::num(c.num).is_some() as usize),
+    );
+    report(
+        "name",
+        time(|c| all.iter().find(|x| x.name == c.name).is_some() as usize),
+        time(|c| iso4217::name(c.name).is_some() as usize),
+    );
+    report(
+        "country",
+        time(|c| all.iter().filter(|x| x.countries.contains(&c.countries[0])).count()),
+        time(|c| iso4217::country(c.countries[0]).len()),
+    );
+
+    let rows = (0..1_000_000).map(|row| all[row % all.len()].alpha3).collect::<Vec<_>>();
+    let bytes = rows.iter().map(|c| [c.as_bytes()[0], c.as_bytes()[1], c.as_bytes()[2]]).collect::<Vec<_>>();
+
+    // Both columns are compared with `alpha3` on the same codes as strings,
+    // so the bytes don't pay for a UTF-8 check the bulk path skips.
+    let per_row = column(|| black_box(&rows).iter().map(|code| iso4217::alpha3(code)).collect::<Vec<_>>());
+
+    report_bulk("str", per_row, column(|| iso4217::parse_many(black_box(&rows))));
+    report_bulk("bytes", per_row, column(|| iso4217::validate_bytes(black_box(&bytes))));
+}
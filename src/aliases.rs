@@ -0,0 +1,97 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+/// Alternative names under which currencies commonly appear in third-party
+/// feeds, keyed by Alpha3 code and sorted by it.
+///
+/// This includes the names used by the ISO 4217 list itself, spellings used
+/// by banks and data vendors, and colloquial names. Every alias names exactly
+/// one currency once case, diacritics and whitespace are ignored, so names
+/// shared by several currencies, like "dollar" or "peso", are left out.
+/// Aliases that fold alike, or like a name or an Alpha3 code, fail
+/// compilation, as the indexes and `lookup` couldn't tell them apart.
+pub(crate) const ALIASES: &[(&str, &[&str])] = &[
+    ("AED", &["UAE dirham", "Emirati dirham"]),
+    ("ARS", &["Argentinian peso"]),
+    ("AUD", &["Aussie dollar", "Aussie"]),
+    ("AWG", &["Aruban guilder"]),
+    ("BAM", &["Convertible Mark", "Bosnian mark", "Bosnia-Herzegovina convertible mark"]),
+    ("BDT", &["Taka"]),
+    ("BGN", &["Lev"]),
+    ("BOB", &["Bolivian boliviano"]),
+    ("BRL", &["Real"]),
+    ("BYR", &["Belarussian ruble", "Belarusian rouble"]),
+    ("CAD", &["Loonie"]),
+    ("CHF", &["Franc suisse", "Schweizer Franken", "Swissie"]),
+    ("CNY", &["Renminbi", "Yuan", "Yuan Renminbi", "RMB", "Chinese renminbi"]),
+    ("CVE", &["Cabo Verde escudo", "Cape Verdean escudo"]),
+    ("CZK", &["Czech crown"]),
+    ("DKK", &["Danish crown", "Danish kroner"]),
+    ("EUR", &["Euros", "Single currency"]),
+    ("GBP", &["Sterling", "British pound", "British pound sterling", "UK pound", "Quid"]),
+    ("HKD", &["HK dollar"]),
+    ("HUF", &["Forint"]),
+    ("ILS", &["New Israeli sheqel", "New Israeli shekel", "Israeli shekel", "Shekel"]),
+    ("ISK", &["Iceland krona", "Icelandic crown"]),
+    ("JPY", &["Yen"]),
+    ("MKD", &["Denar"]),
+    ("MNT", &["Tugrik", "Mongolian tugrik"]),
+    ("MYR", &["Ringgit"]),
+    ("NIO", &["Cordoba Oro", "Nicaraguan cordoba oro"]),
+    ("NOK", &["Norwegian crown", "Norwegian kroner"]),
+    ("NZD", &["Kiwi dollar", "Kiwi"]),
+    ("PEN", &["Nuevo sol", "Peruvian nuevo sol"]),
+    ("PHP", &["Philippine piso"]),
+    ("PLN", &["Zloty"]),
+    ("RUB", &["Russian rouble"]),
+    ("SBD", &["Solomon Islands dollar"]),
+    ("SEK", &["Swedish krona", "Swedish kronor", "Swedish crown"]),
+    ("SGD", &["Sing dollar"]),
+    ("SSP", &["South Sudanese pound"]),
+    ("STD", &["Dobra"]),
+    ("THB", &["Baht"]),
+    ("TJS", &["Tajikistani somoni", "Somoni"]),
+    ("TOP", &["Pa'anga", "Tongan paanga"]),
+    ("TRY", &["Turkish new lira", "Lira"]),
+    ("TWD", &["Taiwan dollar", "Taiwanese dollar", "NT dollar"]),
+    ("UAH", &["Hryvnia", "Ukrainian hryvna"]),
+    ("USD", &["US dollar", "U.S. dollar", "American dollar", "Greenback", "Dollar (US)"]),
+    ("UZS", &["Uzbekistani som", "Uzbek sum"]),
+    ("VEF", &["Bolivar fuerte", "Venezuelan bolivar fuerte"]),
+    ("VND", &["Dong"]),
+    ("XAF", &["Central African CFA franc"]),
+    ("XOF", &["West African CFA franc"]),
+    ("ZAR", &["Rand"]),
+];
+
+/// Returns the aliases of the currency with the given Alpha3 code, which is
+/// empty if it has none.
+pub fn aliases(alpha3: &str) -> &'static [&'static str] {
+    ALIASES
+        .binary_search_by(|&(code, _)| code.cmp(alpha3))
+        .map(|index| ALIASES[index].1)
+        .unwrap_or(&[])
+}
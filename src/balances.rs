@@ -0,0 +1,170 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::collections::btree_map::{self, BTreeMap};
+use core::iter::{FromIterator, Sum};
+use core::ops::{AddAssign, SubAssign};
+
+use {CurrencyCode, Money};
+
+/// A collection of balances in several currencies, holding at most one amount
+/// per currency.
+///
+/// Amounts in different currencies are never combined; adding a `Money` only
+/// affects the balance of its own currency. Balances are iterated in
+/// alphabetical order of their Alpha3 codes.
+///
+/// Arithmetic panics if a balance overflows an `i64`, like the integer
+/// operators it mirrors.
+#[derive(Clone, Debug, Default)]
+pub struct Balances {
+    inner: BTreeMap<&'static str, Money>,
+}
+
+impl Balances {
+    /// Creates an empty collection of balances.
+    pub fn new() -> Balances {
+        Balances::default()
+    }
+
+    /// Adds an amount to the balance of its currency.
+    pub fn add(&mut self, money: Money) {
+        let balance = self.entry(money.currency);
+        balance.amount = balance.amount.checked_add(money.amount).expect("balance overflow");
+    }
+
+    /// Subtracts an amount from the balance of its currency.
+    pub fn sub(&mut self, money: Money) {
+        let balance = self.entry(money.currency);
+        balance.amount = balance.amount.checked_sub(money.amount).expect("balance overflow");
+    }
+
+    /// Returns the balance in the given currency, if there is one.
+    pub fn get(&self, currency: &CurrencyCode) -> Option<Money> {
+        self.inner.get(currency.alpha3).cloned()
+    }
+
+    /// Returns whether there are no balances.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the number of currencies with a balance.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Removes all balances that are zero.
+    pub fn prune_zero(&mut self) {
+        self.inner.retain(|_, money| !money.is_zero());
+    }
+
+    /// Returns an iterator over the balances, ordered by Alpha3 code.
+    pub fn iter(&self) -> BalancesIter<'_> {
+        BalancesIter { inner: self.inner.values() }
+    }
+
+    fn entry(&mut self, currency: &'static CurrencyCode) -> &mut Money {
+        self.inner.entry(currency.alpha3).or_insert_with(|| Money::zero(currency))
+    }
+}
+
+impl AddAssign<Money> for Balances {
+    fn add_assign(&mut self, money: Money) {
+        self.add(money);
+    }
+}
+
+impl SubAssign<Money> for Balances {
+    fn sub_assign(&mut self, money: Money) {
+        self.sub(money);
+    }
+}
+
+impl<'a> AddAssign<&'a Balances> for Balances {
+    fn add_assign(&mut self, other: &'a Balances) {
+        for money in other {
+            self.add(money);
+        }
+    }
+}
+
+impl<'a> SubAssign<&'a Balances> for Balances {
+    fn sub_assign(&mut self, other: &'a Balances) {
+        for money in other {
+            self.sub(money);
+        }
+    }
+}
+
+impl Extend<Money> for Balances {
+    fn extend<I: IntoIterator<Item = Money>>(&mut self, iter: I) {
+        for money in iter {
+            self.add(money);
+        }
+    }
+}
+
+impl FromIterator<Money> for Balances {
+    fn from_iter<I: IntoIterator<Item = Money>>(iter: I) -> Balances {
+        let mut balances = Balances::new();
+        balances.extend(iter);
+        balances
+    }
+}
+
+impl Sum<Money> for Balances {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Balances {
+        iter.collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a Balances {
+    type Item = Money;
+    type IntoIter = BalancesIter<'a>;
+
+    fn into_iter(self) -> BalancesIter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the balances of a `Balances` collection.
+#[derive(Clone, Debug)]
+pub struct BalancesIter<'a> {
+    inner: btree_map::Values<'a, &'static str, Money>,
+}
+
+impl<'a> Iterator for BalancesIter<'a> {
+    type Item = Money;
+
+    fn next(&mut self) -> Option<Money> {
+        self.inner.next().cloned()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,175 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::str;
+
+use index::{self, NONE};
+use {Currency, CurrencyCode, ParseCurrencyError};
+
+/// Rows looked up before checking whether any of them failed.
+const CHUNK: usize = 64;
+
+/// The result of validating a column of currency codes.
+///
+/// Rows are stored as the dense index of their currency, one byte each, and
+/// converted to a `Currency` when read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Validation {
+    indexes: Vec<u8>,
+    errors: Vec<RowError>,
+}
+
+impl Validation {
+    /// Returns the currency of a row, or `None` if the row failed or is past
+    /// the end of the column.
+    pub fn currency(&self, row: usize) -> Option<Currency> {
+        self.indexes.get(row).and_then(|&index| Currency::from_index(index))
+    }
+
+    /// Returns an iterator over the currency of each row, with `None` for a
+    /// row that failed.
+    pub fn currencies(&self) -> impl Iterator<Item = Option<Currency>> + '_ {
+        self.indexes.iter().map(|&index| Currency::from_index(index))
+    }
+
+    /// Returns the rows that failed, in order.
+    pub fn errors(&self) -> &[RowError] {
+        &self.errors
+    }
+
+    /// Returns whether every row holds a valid code.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns whether the column has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+
+    /// Returns the number of rows in the column.
+    pub fn len(&self) -> usize {
+        self.indexes.len()
+    }
+}
+
+/// A row of a column that does not hold a valid currency code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RowError {
+    /// Index of the row
+    pub row: usize,
+    /// Why the row is invalid
+    pub error: ParseCurrencyError,
+}
+
+/// Validates a column of Alpha3 codes, returning the currency of each row
+/// and the rows that failed with the reason, as `str::parse` would report.
+///
+/// Rows are validated a chunk at a time: their codes are copied out, their
+/// keys in the Alpha3 index are computed without branching on the letters,
+/// then looked up, and rows are only examined further if one of them failed.
+///
+/// This is not much faster than calling [`alpha3`](fn.alpha3.html) on each
+/// row, as following each string to its bytes costs more than looking the
+/// code up; the gain is in storing one byte per row and in the row errors.
+/// Codes stored as fixed-width bytes skip that step, and
+/// [`validate_bytes`](fn.validate_bytes.html) checks them nearly twice as
+/// fast as per-row lookups; see `examples/bench.rs`.
+pub fn parse_many(codes: &[&str]) -> Validation {
+    let bytes = |code: &&str| match *code.as_bytes() {
+        [a, b, c] => [a, b, c],
+        _ => [0; 3],
+    };
+
+    validate(codes, bytes, |code| code.parse::<Currency>().err())
+}
+
+/// Validates a column of Alpha3 codes stored as three bytes each, as read
+/// from fixed-width files or columnar storage, like
+/// [`parse_many`](fn.parse_many.html).
+///
+/// Bytes that aren't UTF-8 are reported as a `\u{FFFD}` invalid character,
+/// at the position of the character they start, like any other.
+pub fn validate_bytes(codes: &[[u8; 3]]) -> Validation {
+    validate(codes, |&code| code, |code| match str::from_utf8(code) {
+        Ok(code) => <&CurrencyCode>::try_from(code).err(),
+        Err(error) => {
+            let valid = str::from_utf8(&code[..error.valid_up_to()]).expect("the prefix is valid UTF-8");
+
+            Some(ParseCurrencyError::InvalidCharacter { position: valid.chars().count(), found: '\u{FFFD}' })
+        },
+    })
+}
+
+fn validate<T, B, E>(rows: &[T], bytes: B, error: E) -> Validation
+where
+    B: Fn(&T) -> [u8; 3],
+    E: Fn(&T) -> Option<ParseCurrencyError>,
+{
+    let mut indexes = Vec::with_capacity(rows.len());
+    let mut failed = false;
+
+    for chunk in rows.chunks(CHUNK) {
+        let mut codes = [[0; 3]; CHUNK];
+        let mut keys = [0; CHUNK];
+
+        for (code, row) in codes.iter_mut().zip(chunk) {
+            *code = bytes(row);
+        }
+
+        // Keys are computed apart from reading the rows and from the
+        // lookups, which lets them be computed for several rows at once.
+        for (key, &code) in keys.iter_mut().zip(&codes) {
+            *key = index::alpha3_key(code);
+        }
+
+        indexes.extend(keys[..chunk.len()].iter().map(|&key| {
+            let index = index::alpha3_position(key);
+
+            failed |= index == NONE;
+            index
+        }));
+    }
+
+    if !failed {
+        return Validation { indexes, errors: Vec::new() };
+    }
+
+    let errors = indexes
+        .iter()
+        .enumerate()
+        .filter(|&(_, &index)| index == NONE)
+        .map(|(row, _)| RowError {
+            row,
+            // A row that failed the lookup always fails to parse.
+            error: error(&rows[row]).unwrap_or(ParseCurrencyError::Unknown { suggestion: None }),
+        })
+        .collect();
+
+    Validation { indexes, errors }
+}
@@ -26,7 +26,7 @@
 
 use CurrencyCode;
 
-const ALL: [CurrencyCode; 155] = [
+pub(crate) static ALL: [CurrencyCode; 155] = [
     // Sorted by num.
     CurrencyCode {
         alpha3: "ALL",
@@ -0,0 +1,438 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::iter::FromIterator;
+use core::ops::{BitAnd, BitOr, Index, Sub};
+
+use Currency;
+
+const WORDS: usize = Currency::COUNT.div_ceil(64);
+
+/// A set of currencies stored as one bit per currency.
+///
+/// Sets take 24 bytes, are `Copy`, and can be built in constants:
+///
+/// ```rust
+/// use iso4217::{Currency, CurrencySet};
+///
+/// const SUPPORTED: CurrencySet = CurrencySet::new().with(Currency::EUR).with(Currency::USD);
+///
+/// assert!(SUPPORTED.contains(Currency::EUR));
+/// assert!(!SUPPORTED.contains(Currency::JPY));
+/// ```
+///
+/// Iteration follows the order of [`all`](fn.all.html).
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct CurrencySet {
+    bits: [u64; WORDS],
+}
+
+impl CurrencySet {
+    /// Creates an empty set.
+    pub const fn new() -> CurrencySet {
+        CurrencySet { bits: [0; WORDS] }
+    }
+
+    /// Creates a set holding every currency.
+    pub const fn full() -> CurrencySet {
+        let mut set = CurrencySet::new();
+        let mut index = 0;
+
+        while index < Currency::COUNT {
+            set.bits[index / 64] |= 1 << (index % 64);
+            index += 1;
+        }
+
+        set
+    }
+
+    /// Creates a set holding the given currencies.
+    pub const fn from_slice(currencies: &[Currency]) -> CurrencySet {
+        let mut set = CurrencySet::new();
+        let mut i = 0;
+
+        while i < currencies.len() {
+            set.insert(currencies[i]);
+            i += 1;
+        }
+
+        set
+    }
+
+    /// Returns the set with a currency added.
+    pub const fn with(mut self, currency: Currency) -> CurrencySet {
+        self.insert(currency);
+
+        self
+    }
+
+    /// Adds a currency, returning whether it was not already present.
+    pub const fn insert(&mut self, currency: Currency) -> bool {
+        let (word, bit) = position(currency);
+        let absent = self.bits[word] & bit == 0;
+
+        self.bits[word] |= bit;
+
+        absent
+    }
+
+    /// Removes a currency, returning whether it was present.
+    pub const fn remove(&mut self, currency: Currency) -> bool {
+        let (word, bit) = position(currency);
+        let present = self.bits[word] & bit != 0;
+
+        self.bits[word] &= !bit;
+
+        present
+    }
+
+    /// Returns whether the set holds a currency.
+    pub const fn contains(&self, currency: Currency) -> bool {
+        let (word, bit) = position(currency);
+
+        self.bits[word] & bit != 0
+    }
+
+    /// Returns the number of currencies in the set.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut word = 0;
+
+        while word < WORDS {
+            len += self.bits[word].count_ones() as usize;
+            word += 1;
+        }
+
+        len
+    }
+
+    /// Returns whether the set is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the currencies in either set.
+    pub const fn union(&self, other: &CurrencySet) -> CurrencySet {
+        self.combine(other, Op::Union)
+    }
+
+    /// Returns the currencies in both sets.
+    pub const fn intersection(&self, other: &CurrencySet) -> CurrencySet {
+        self.combine(other, Op::Intersection)
+    }
+
+    /// Returns the currencies in this set but not the other.
+    pub const fn difference(&self, other: &CurrencySet) -> CurrencySet {
+        self.combine(other, Op::Difference)
+    }
+
+    /// Returns whether every currency of this set is in the other.
+    pub const fn is_subset(&self, other: &CurrencySet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Returns an iterator over the currencies in the set.
+    pub fn iter(&self) -> CurrencySetIter {
+        CurrencySetIter { bits: self.bits, word: 0 }
+    }
+
+    const fn combine(&self, other: &CurrencySet, op: Op) -> CurrencySet {
+        let mut set = CurrencySet::new();
+        let mut word = 0;
+
+        while word < WORDS {
+            let (a, b) = (self.bits[word], other.bits[word]);
+
+            set.bits[word] = match op {
+                Op::Union => a | b,
+                Op::Intersection => a & b,
+                Op::Difference => a & !b,
+            };
+            word += 1;
+        }
+
+        set
+    }
+}
+
+impl Debug for CurrencySet {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl BitOr for CurrencySet {
+    type Output = CurrencySet;
+
+    fn bitor(self, other: CurrencySet) -> CurrencySet {
+        self.union(&other)
+    }
+}
+
+impl BitAnd for CurrencySet {
+    type Output = CurrencySet;
+
+    fn bitand(self, other: CurrencySet) -> CurrencySet {
+        self.intersection(&other)
+    }
+}
+
+impl Sub for CurrencySet {
+    type Output = CurrencySet;
+
+    fn sub(self, other: CurrencySet) -> CurrencySet {
+        self.difference(&other)
+    }
+}
+
+impl Extend<Currency> for CurrencySet {
+    fn extend<I: IntoIterator<Item = Currency>>(&mut self, iter: I) {
+        for currency in iter {
+            self.insert(currency);
+        }
+    }
+}
+
+impl FromIterator<Currency> for CurrencySet {
+    fn from_iter<I: IntoIterator<Item = Currency>>(iter: I) -> CurrencySet {
+        let mut set = CurrencySet::new();
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl IntoIterator for CurrencySet {
+    type Item = Currency;
+    type IntoIter = CurrencySetIter;
+
+    fn into_iter(self) -> CurrencySetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CurrencySet {
+    type Item = Currency;
+    type IntoIter = CurrencySetIter;
+
+    fn into_iter(self) -> CurrencySetIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the currencies of a [`CurrencySet`](struct.CurrencySet.html).
+#[derive(Clone, Debug)]
+pub struct CurrencySetIter {
+    bits: [u64; WORDS],
+    word: usize,
+}
+
+impl Iterator for CurrencySetIter {
+    type Item = Currency;
+
+    fn next(&mut self) -> Option<Currency> {
+        while self.word < WORDS {
+            let bits = self.bits[self.word];
+
+            if bits != 0 {
+                self.bits[self.word] &= bits - 1;
+
+                return Currency::from_index((self.word * 64) as u8 + bits.trailing_zeros() as u8);
+            }
+
+            self.word += 1;
+        }
+
+        None
+    }
+}
+
+/// A map from currencies to values, stored as one slot per currency.
+///
+/// Lookups index an array by the currency's dense index, and maps of `Copy`
+/// values can be built in constants:
+///
+/// ```rust
+/// use iso4217::{Currency, CurrencyMap};
+///
+/// const LIMITS: CurrencyMap<u64> = CurrencyMap::new()
+///     .with(Currency::EUR, 10_000_00)
+///     .with(Currency::JPY, 1_500_000);
+///
+/// assert_eq!(LIMITS.get(Currency::EUR), Some(&10_000_00));
+/// assert_eq!(LIMITS.get(Currency::USD), None);
+/// ```
+///
+/// Iteration follows the order of [`all`](fn.all.html).
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct CurrencyMap<V> {
+    values: [Option<V>; Currency::COUNT],
+}
+
+impl<V> CurrencyMap<V> {
+    /// Creates an empty map.
+    pub const fn new() -> CurrencyMap<V> {
+        CurrencyMap { values: [const { None }; Currency::COUNT] }
+    }
+
+    /// Returns the value for a currency.
+    pub const fn get(&self, currency: Currency) -> Option<&V> {
+        self.values[currency.index() as usize].as_ref()
+    }
+
+    /// Returns the value for a currency, mutably.
+    pub fn get_mut(&mut self, currency: Currency) -> Option<&mut V> {
+        self.values[currency.index() as usize].as_mut()
+    }
+
+    /// Sets the value for a currency, returning the previous one.
+    pub fn insert(&mut self, currency: Currency, value: V) -> Option<V> {
+        self.values[currency.index() as usize].replace(value)
+    }
+
+    /// Removes the value for a currency, returning it.
+    pub fn remove(&mut self, currency: Currency) -> Option<V> {
+        self.values[currency.index() as usize].take()
+    }
+
+    /// Returns whether the map has a value for a currency.
+    pub const fn contains_key(&self, currency: Currency) -> bool {
+        self.values[currency.index() as usize].is_some()
+    }
+
+    /// Returns the number of currencies with a value.
+    pub fn len(&self) -> usize {
+        self.values.iter().filter(|value| value.is_some()).count()
+    }
+
+    /// Returns whether no currency has a value.
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(Option::is_none)
+    }
+
+    /// Returns the set of currencies with a value.
+    pub fn keys(&self) -> CurrencySet {
+        self.iter().map(|(currency, _)| currency).collect()
+    }
+
+    /// Returns an iterator over the currencies with a value and their values.
+    pub fn iter(&self) -> CurrencyMapIter<'_, V> {
+        CurrencyMapIter { values: self.values.iter().enumerate() }
+    }
+}
+
+impl<V: Copy> CurrencyMap<V> {
+    /// Returns the map with the value for a currency set.
+    pub const fn with(mut self, currency: Currency, value: V) -> CurrencyMap<V> {
+        self.values[currency.index() as usize] = Some(value);
+
+        self
+    }
+}
+
+impl<V: Debug> Debug for CurrencyMap<V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Default for CurrencyMap<V> {
+    fn default() -> CurrencyMap<V> {
+        CurrencyMap::new()
+    }
+}
+
+/// Returns the value for a currency, panicking if it has none.
+impl<V> Index<Currency> for CurrencyMap<V> {
+    type Output = V;
+
+    fn index(&self, currency: Currency) -> &V {
+        match self.get(currency) {
+            Some(value) => value,
+            None => panic!("no value for {}", currency),
+        }
+    }
+}
+
+impl<V> Extend<(Currency, V)> for CurrencyMap<V> {
+    fn extend<I: IntoIterator<Item = (Currency, V)>>(&mut self, iter: I) {
+        for (currency, value) in iter {
+            self.insert(currency, value);
+        }
+    }
+}
+
+impl<V> FromIterator<(Currency, V)> for CurrencyMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Currency, V)>>(iter: I) -> CurrencyMap<V> {
+        let mut map = CurrencyMap::new();
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<'a, V> IntoIterator for &'a CurrencyMap<V> {
+    type Item = (Currency, &'a V);
+    type IntoIter = CurrencyMapIter<'a, V>;
+
+    fn into_iter(self) -> CurrencyMapIter<'a, V> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`CurrencyMap`](struct.CurrencyMap.html).
+#[derive(Clone, Debug)]
+pub struct CurrencyMapIter<'a, V: 'a> {
+    values: ::core::iter::Enumerate<::core::slice::Iter<'a, Option<V>>>,
+}
+
+impl<'a, V> Iterator for CurrencyMapIter<'a, V> {
+    type Item = (Currency, &'a V);
+
+    fn next(&mut self) -> Option<(Currency, &'a V)> {
+        for (index, value) in &mut self.values {
+            if let (Some(value), Some(currency)) = (value.as_ref(), Currency::from_index(index as u8)) {
+                return Some((currency, value));
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Union,
+    Intersection,
+    Difference,
+}
+
+const fn position(currency: Currency) -> (usize, u64) {
+    let index = currency.index() as usize;
+
+    (index / 64, 1 << (index % 64))
+}
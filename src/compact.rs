@@ -0,0 +1,493 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::FromStr;
+
+use codes::ALL;
+use {CurrencyCode, ParseCurrencyError, index};
+
+/// A currency defined by ISO 4217, stored in two bytes.
+///
+/// The discriminant of each variant is the currency's numeric code, so
+/// `Currency::EUR as u16` is 978. Each currency also has a dense index from 0
+/// to `Currency::COUNT - 1`, following the order of [`all`](fn.all.html),
+/// for storage in a single byte or as an array position. Both convert back
+/// to the full `CurrencyCode` by indexing a table.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u16)]
+pub enum Currency {
+    /// Albanian lek
+    ALL = 8,
+    /// Algerian dinar
+    DZD = 12,
+    /// Argentine peso
+    ARS = 32,
+    /// Australian dollar
+    AUD = 36,
+    /// Bahamian dollar
+    BSD = 44,
+    /// Bahraini dinar
+    BHD = 48,
+    /// Bangladeshi taka
+    BDT = 50,
+    /// Armenian dram
+    AMD = 51,
+    /// Barbados dollar
+    BBD = 52,
+    /// Bermudian dollar
+    BMD = 60,
+    /// Bhutanese ngultrum
+    BTN = 64,
+    /// Boliviano
+    BOB = 68,
+    /// Botswana pula
+    BWP = 72,
+    /// Belize dollar
+    BZD = 84,
+    /// Soloman Islands dollar
+    SBD = 90,
+    /// Brunei dollar
+    BND = 96,
+    /// Myanmar kyat
+    MMK = 104,
+    /// Burundian franc
+    BIF = 108,
+    /// Cambodian riel
+    KHR = 116,
+    /// Canadian dollar
+    CAD = 124,
+    /// Cape Verde escudo
+    CVE = 132,
+    /// Cayman Islands dollar
+    KYD = 136,
+    /// Sri Lankan rupee
+    LKR = 144,
+    /// Chilean peso
+    CLP = 152,
+    /// Chinese yuan
+    CNY = 156,
+    /// Colombian peso
+    COP = 170,
+    /// Comoro franc
+    KMF = 174,
+    /// Costa Rican colon
+    CRC = 188,
+    /// Croatian kuna
+    HRK = 191,
+    /// Cuban peso
+    CUP = 192,
+    /// Czech koruna
+    CZK = 203,
+    /// Danish krone
+    DKK = 208,
+    /// Dominican peso
+    DOP = 214,
+    /// Ethiopian birr
+    ETB = 230,
+    /// Eritrean nakfa
+    ERN = 232,
+    /// Falkland Islands pound
+    FKP = 238,
+    /// Fiji dollar
+    FJD = 242,
+    /// Djiboutian franc
+    DJF = 262,
+    /// Gambian dalasi
+    GMD = 270,
+    /// Gibraltar pound
+    GIP = 292,
+    /// Guatemalan quetzal
+    GTQ = 320,
+    /// Guinean franc
+    GNF = 324,
+    /// Guyanese dollar
+    GYD = 328,
+    /// Haitian gourde
+    HTG = 332,
+    /// Honduran lempira
+    HNL = 340,
+    /// Hong Kong dollar
+    HKD = 344,
+    /// Hungarian forint
+    HUF = 348,
+    /// Icelandic króna
+    ISK = 352,
+    /// Indian rupee
+    INR = 356,
+    /// Indonesian rupiah
+    IDR = 360,
+    /// Iranian rial
+    IRR = 364,
+    /// Iraqi dinar
+    IQD = 368,
+    /// Israeli new shekel
+    ILS = 376,
+    /// Jamaican dollar
+    KMD = 388,
+    /// Japanese yen
+    JPY = 392,
+    /// Kazakhstani tenge
+    KZT = 398,
+    /// Jordanian dinar
+    JOD = 400,
+    /// Kenyan shilling
+    KES = 404,
+    /// North Korean won
+    KPW = 408,
+    /// South Korean won
+    KRW = 410,
+    /// Kuwaiti dinar
+    KWD = 414,
+    /// Kyrgyzstani som
+    KGS = 417,
+    /// Lao kip
+    LAK = 418,
+    /// Lebanese pound
+    LBP = 422,
+    /// Lesotho loti
+    LSL = 426,
+    /// Liberian dollar
+    LRD = 430,
+    /// Libyan dinar
+    LYD = 434,
+    /// Macanese pataca
+    MOP = 446,
+    /// Malawian kwacha
+    MWK = 454,
+    /// Malaysian ringgit
+    MYR = 458,
+    /// Maldivian rufiyaa
+    MVR = 462,
+    /// Mauritanian ouguiya
+    MRO = 478,
+    /// Mauritian rupee
+    MUR = 480,
+    /// Mexican peso
+    MXN = 484,
+    /// Mongolian tögrög
+    MNT = 496,
+    /// Moldovan leu
+    MDL = 498,
+    /// Moroccan dirham
+    MAD = 504,
+    /// Omani rial
+    OMR = 512,
+    /// Namibian dollar
+    NAD = 516,
+    /// Nepalese rupee
+    NPR = 524,
+    /// Netherlands Antillean guilder
+    ANG = 532,
+    /// Aruban florin
+    AWG = 533,
+    /// Vanuatu vatu
+    VUV = 548,
+    /// New Zealand dollar
+    NZD = 554,
+    /// Nicaraguan córdoba
+    NIO = 558,
+    /// Nigerian naira
+    NGN = 566,
+    /// Norwegian krone
+    NOK = 578,
+    /// Pakistani rupee
+    PKR = 586,
+    /// Panamanian balboa
+    PAB = 590,
+    /// Papua New Guinean kina
+    PGK = 598,
+    /// Paraguayan guaraní
+    PYG = 600,
+    /// Peruvian Sol
+    PEN = 604,
+    /// Philippine peso
+    PHP = 608,
+    /// Qatari riyal
+    QAR = 634,
+    /// Russian ruble
+    RUB = 643,
+    /// Rwandan franc
+    RWF = 646,
+    /// Saint Helena pound
+    SHP = 654,
+    /// São Tomé and Príncipe dobra
+    STD = 678,
+    /// Saudi riyal
+    SAR = 682,
+    /// Seychelles rupee
+    SCR = 690,
+    /// Sierra Leonean leone
+    SLL = 694,
+    /// Singapore dollar
+    SGD = 702,
+    /// Vietnamese dong
+    VND = 704,
+    /// Somali shilling
+    SOS = 706,
+    /// South African rand
+    ZAR = 710,
+    /// South Sudeanese pound
+    SSP = 728,
+    /// Swazi lilangeni
+    SZL = 748,
+    /// Swedish krona/kronor
+    SEK = 752,
+    /// Swiss franc
+    CHF = 756,
+    /// Syrian pound
+    SYP = 760,
+    /// Thai baht
+    THB = 764,
+    /// Tongan pa'anga
+    TOP = 776,
+    /// Trinidad and Tobago dollar
+    TTD = 780,
+    /// United Arab Emirates dirham
+    AED = 784,
+    /// Tunisian dinar
+    TND = 788,
+    /// Ugandan shilling
+    UGX = 800,
+    /// Macedonian denar
+    MKD = 807,
+    /// Egyptian pound
+    EGP = 818,
+    /// Pound sterling
+    GBP = 826,
+    /// Tanzanian shilling
+    TZS = 834,
+    /// United States dollar
+    USD = 840,
+    /// Uruguayan peso
+    UYU = 858,
+    /// Uzbekistan som
+    UZS = 860,
+    /// Samoan tala
+    WST = 882,
+    /// Yemeni rial
+    YER = 886,
+    /// New Taiwan dollar
+    TWD = 901,
+    /// Cuban convertible peso
+    CUC = 931,
+    /// Turkmenistani manat
+    TMT = 934,
+    /// Ghanaian cedi
+    GHS = 936,
+    /// Venezuelan bolivar
+    VEF = 937,
+    /// Sudanese pound
+    SDG = 938,
+    /// Serbian dinar
+    RSD = 941,
+    /// Mozambican metical
+    MZN = 943,
+    /// Azerbaijani manat
+    AZN = 944,
+    /// Romanian leu
+    RON = 946,
+    /// Turkish lira
+    TRY = 949,
+    /// CFA franc BEAC
+    XAF = 950,
+    /// East Caribbean dollar
+    XCD = 951,
+    /// CFA franc BCEAO
+    XOF = 952,
+    /// CFP franc
+    XPF = 953,
+    /// Zambian kwacha
+    ZMW = 967,
+    /// Surinamese dollar
+    SRD = 968,
+    /// Malagasy ariary
+    MGA = 969,
+    /// Afghan afghani
+    AFN = 971,
+    /// Tajikstani somoni
+    TJS = 972,
+    /// Angolan kwanza
+    AOA = 973,
+    /// Belarusian ruble
+    BYR = 974,
+    /// Bulgarian lev
+    BGN = 975,
+    /// Congolese franc
+    CDF = 976,
+    /// Bosnia and Herzegovina convertible mark
+    BAM = 977,
+    /// Euro
+    EUR = 978,
+    /// Ukrainian hryvnia
+    UAH = 980,
+    /// Georgian lari
+    GEL = 981,
+    /// Polish złoty
+    PLN = 985,
+    /// Brazilian real
+    BRL = 986,
+}
+
+/// The currencies in the order of `all`, by dense index.
+static BY_INDEX: [Currency; Currency::COUNT] = dense([
+    Currency::ALL, Currency::DZD, Currency::ARS, Currency::AUD, Currency::BSD, Currency::BHD, Currency::BDT, Currency::AMD,
+    Currency::BBD, Currency::BMD, Currency::BTN, Currency::BOB, Currency::BWP, Currency::BZD, Currency::SBD, Currency::BND,
+    Currency::MMK, Currency::BIF, Currency::KHR, Currency::CAD, Currency::CVE, Currency::KYD, Currency::LKR, Currency::CLP,
+    Currency::CNY, Currency::COP, Currency::KMF, Currency::CRC, Currency::HRK, Currency::CUP, Currency::CZK, Currency::DKK,
+    Currency::DOP, Currency::ETB, Currency::ERN, Currency::FKP, Currency::FJD, Currency::DJF, Currency::GMD, Currency::GIP,
+    Currency::GTQ, Currency::GNF, Currency::GYD, Currency::HTG, Currency::HNL, Currency::HKD, Currency::HUF, Currency::ISK,
+    Currency::INR, Currency::IDR, Currency::IRR, Currency::IQD, Currency::ILS, Currency::KMD, Currency::JPY, Currency::KZT,
+    Currency::JOD, Currency::KES, Currency::KPW, Currency::KRW, Currency::KWD, Currency::KGS, Currency::LAK, Currency::LBP,
+    Currency::LSL, Currency::LRD, Currency::LYD, Currency::MOP, Currency::MWK, Currency::MYR, Currency::MVR, Currency::MRO,
+    Currency::MUR, Currency::MXN, Currency::MNT, Currency::MDL, Currency::MAD, Currency::OMR, Currency::NAD, Currency::NPR,
+    Currency::ANG, Currency::AWG, Currency::VUV, Currency::NZD, Currency::NIO, Currency::NGN, Currency::NOK, Currency::PKR,
+    Currency::PAB, Currency::PGK, Currency::PYG, Currency::PEN, Currency::PHP, Currency::QAR, Currency::RUB, Currency::RWF,
+    Currency::SHP, Currency::STD, Currency::SAR, Currency::SCR, Currency::SLL, Currency::SGD, Currency::VND, Currency::SOS,
+    Currency::ZAR, Currency::SSP, Currency::SZL, Currency::SEK, Currency::CHF, Currency::SYP, Currency::THB, Currency::TOP,
+    Currency::TTD, Currency::AED, Currency::TND, Currency::UGX, Currency::MKD, Currency::EGP, Currency::GBP, Currency::TZS,
+    Currency::USD, Currency::UYU, Currency::UZS, Currency::WST, Currency::YER, Currency::TWD, Currency::CUC, Currency::TMT,
+    Currency::GHS, Currency::VEF, Currency::SDG, Currency::RSD, Currency::MZN, Currency::AZN, Currency::RON, Currency::TRY,
+    Currency::XAF, Currency::XCD, Currency::XOF, Currency::XPF, Currency::ZMW, Currency::SRD, Currency::MGA, Currency::AFN,
+    Currency::TJS, Currency::AOA, Currency::BYR, Currency::BGN, Currency::CDF, Currency::BAM, Currency::EUR, Currency::UAH,
+    Currency::GEL, Currency::PLN, Currency::BRL,
+]);
+
+impl Currency {
+    /// Number of currencies, one more than the largest dense index.
+    pub const COUNT: usize = ALL.len();
+
+    /// Returns the currency with the given numeric code.
+    pub const fn from_num(num: u16) -> Option<Currency> {
+        match index::num_value(num) {
+            Some(currency) => Currency::from_code(currency),
+            None => None,
+        }
+    }
+
+    /// Returns the currency with the given dense index.
+    pub const fn from_index(index: u8) -> Option<Currency> {
+        if (index as usize) < Currency::COUNT {
+            Some(BY_INDEX[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the currency for a `CurrencyCode`, unless it is a withdrawn
+    /// one.
+    pub const fn from_code(code: &CurrencyCode) -> Option<Currency> {
+        match index::alpha3(code.alpha3) {
+            Some(currency) => Some(BY_INDEX[index::position(currency)]),
+            None => None,
+        }
+    }
+
+    /// Returns the dense index of the currency, its position in `all`.
+    pub const fn index(self) -> u8 {
+        index::num_position(self as u16)
+    }
+
+    /// Returns the numeric code of the currency.
+    pub const fn num(self) -> u16 {
+        self as u16
+    }
+
+    /// Returns the data of the currency.
+    pub const fn code(self) -> &'static CurrencyCode {
+        &ALL[self.index() as usize]
+    }
+}
+
+/// Currencies are ordered by Alpha3 code, like `CurrencyCode`, rather than
+/// by numeric code.
+impl Ord for Currency {
+    fn cmp(&self, other: &Currency) -> Ordering {
+        self.code().cmp(other.code())
+    }
+}
+
+impl PartialOrd for Currency {
+    fn partial_cmp(&self, other: &Currency) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<Currency> for &'static CurrencyCode {
+    fn from(currency: Currency) -> &'static CurrencyCode {
+        currency.code()
+    }
+}
+
+impl<'a> TryFrom<&'a CurrencyCode> for Currency {
+    type Error = ParseCurrencyError;
+
+    fn try_from(code: &'a CurrencyCode) -> Result<Currency, ParseCurrencyError> {
+        <&CurrencyCode>::try_from(code.alpha3).map(|code| BY_INDEX[index::position(code)])
+    }
+}
+
+impl TryFrom<u16> for Currency {
+    type Error = ParseCurrencyError;
+
+    /// Returns the currency with the given numeric code.
+    fn try_from(num: u16) -> Result<Currency, ParseCurrencyError> {
+        <&CurrencyCode>::try_from(num).map(|code| BY_INDEX[index::position(code)])
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    /// Parses an Alpha3 code, as in `"EUR".parse::<Currency>()`.
+    fn from_str(s: &str) -> Result<Currency, ParseCurrencyError> {
+        <&CurrencyCode>::try_from(s).map(|code| BY_INDEX[index::position(code)])
+    }
+}
+
+/// Prints the Alpha3 code, or the name with the alternate flag, like
+/// `CurrencyCode`.
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(self.code(), f)
+    }
+}
+
+/// Checks at compile time that each currency sits at its position in `all`,
+/// and that dense indexes fit in a byte without reaching `index::NONE`.
+const fn dense(table: [Currency; Currency::COUNT]) -> [Currency; Currency::COUNT] {
+    assert!(Currency::COUNT <= index::NONE as usize, "dense indexes must fit in a byte");
+    let mut i = 0;
+
+    while i < table.len() {
+        assert!(index::num_position(table[i] as u16) as usize == i, "currencies must follow the order of all");
+        i += 1;
+    }
+
+    table
+}
@@ -0,0 +1,310 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+/// A continental region, as grouped by the UN M49 standard.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Region {
+    /// Africa
+    Africa,
+    /// North, Central and South America and the Caribbean
+    Americas,
+    /// Antarctica
+    Antarctica,
+    /// Asia, including the Middle East
+    Asia,
+    /// Europe
+    Europe,
+    /// Australia, New Zealand and the Pacific islands
+    Oceania,
+}
+
+// English short names and regions of the countries in
+// `CurrencyCode::countries`, sorted by Alpha2 code. "UK" is listed as it is
+// used for the United Kingdom instead of "GB".
+const COUNTRIES: [(&str, &str, Region); 242] = [
+    ("AD", "Andorra", Region::Europe),
+    ("AE", "United Arab Emirates", Region::Asia),
+    ("AF", "Afghanistan", Region::Asia),
+    ("AG", "Antigua and Barbuda", Region::Americas),
+    ("AI", "Anguilla", Region::Americas),
+    ("AL", "Albania", Region::Europe),
+    ("AM", "Armenia", Region::Asia),
+    ("AO", "Angola", Region::Africa),
+    ("AQ", "Antarctica", Region::Antarctica),
+    ("AR", "Argentina", Region::Americas),
+    ("AS", "American Samoa", Region::Oceania),
+    ("AT", "Austria", Region::Europe),
+    ("AU", "Australia", Region::Oceania),
+    ("AW", "Aruba", Region::Americas),
+    ("AZ", "Azerbaijan", Region::Asia),
+    ("BA", "Bosnia and Herzegovina", Region::Europe),
+    ("BB", "Barbados", Region::Americas),
+    ("BD", "Bangladesh", Region::Asia),
+    ("BE", "Belgium", Region::Europe),
+    ("BF", "Burkina Faso", Region::Africa),
+    ("BG", "Bulgaria", Region::Europe),
+    ("BH", "Bahrain", Region::Asia),
+    ("BI", "Burundi", Region::Africa),
+    ("BJ", "Benin", Region::Africa),
+    ("BL", "Saint Barthélemy", Region::Americas),
+    ("BM", "Bermuda", Region::Americas),
+    ("BN", "Brunei Darussalam", Region::Asia),
+    ("BO", "Bolivia", Region::Americas),
+    ("BQ", "Bonaire, Sint Eustatius and Saba", Region::Americas),
+    ("BR", "Brazil", Region::Americas),
+    ("BS", "Bahamas", Region::Americas),
+    ("BT", "Bhutan", Region::Asia),
+    ("BV", "Bouvet Island", Region::Americas),
+    ("BW", "Botswana", Region::Africa),
+    ("BY", "Belarus", Region::Europe),
+    ("BZ", "Belize", Region::Americas),
+    ("CA", "Canada", Region::Americas),
+    ("CC", "Cocos (Keeling) Islands", Region::Oceania),
+    ("CD", "Democratic Republic of the Congo", Region::Africa),
+    ("CF", "Central African Republic", Region::Africa),
+    ("CG", "Congo", Region::Africa),
+    ("CH", "Switzerland", Region::Europe),
+    ("CI", "Côte d'Ivoire", Region::Africa),
+    ("CK", "Cook Islands", Region::Oceania),
+    ("CL", "Chile", Region::Americas),
+    ("CM", "Cameroon", Region::Africa),
+    ("CN", "China", Region::Asia),
+    ("CO", "Colombia", Region::Americas),
+    ("CR", "Costa Rica", Region::Americas),
+    ("CU", "Cuba", Region::Americas),
+    ("CV", "Cabo Verde", Region::Africa),
+    ("CW", "Curaçao", Region::Americas),
+    ("CX", "Christmas Island", Region::Oceania),
+    ("CY", "Cyprus", Region::Asia),
+    ("CZ", "Czechia", Region::Europe),
+    ("DE", "Germany", Region::Europe),
+    ("DJ", "Djibouti", Region::Africa),
+    ("DK", "Denmark", Region::Europe),
+    ("DM", "Dominica", Region::Americas),
+    ("DO", "Dominican Republic", Region::Americas),
+    ("DZ", "Algeria", Region::Africa),
+    ("EC", "Ecuador", Region::Americas),
+    ("EE", "Estonia", Region::Europe),
+    ("EG", "Egypt", Region::Africa),
+    ("ER", "Eritrea", Region::Africa),
+    ("ES", "Spain", Region::Europe),
+    ("ET", "Ethiopia", Region::Africa),
+    ("FI", "Finland", Region::Europe),
+    ("FJ", "Fiji", Region::Oceania),
+    ("FK", "Falkland Islands", Region::Americas),
+    ("FM", "Micronesia", Region::Oceania),
+    ("FO", "Faroe Islands", Region::Europe),
+    ("FR", "France", Region::Europe),
+    ("GA", "Gabon", Region::Africa),
+    ("GD", "Grenada", Region::Americas),
+    ("GE", "Georgia", Region::Asia),
+    ("GG", "Guernsey", Region::Europe),
+    ("GH", "Ghana", Region::Africa),
+    ("GI", "Gibraltar", Region::Europe),
+    ("GL", "Greenland", Region::Americas),
+    ("GM", "Gambia", Region::Africa),
+    ("GN", "Guinea", Region::Africa),
+    ("GP", "Guadeloupe", Region::Americas),
+    ("GQ", "Equatorial Guinea", Region::Africa),
+    ("GR", "Greece", Region::Europe),
+    ("GS", "South Georgia and the South Sandwich Islands", Region::Americas),
+    ("GT", "Guatemala", Region::Americas),
+    ("GU", "Guam", Region::Oceania),
+    ("GW", "Guinea-Bissau", Region::Africa),
+    ("GY", "Guyana", Region::Americas),
+    ("HK", "Hong Kong", Region::Asia),
+    ("HM", "Heard Island and McDonald Islands", Region::Oceania),
+    ("HN", "Honduras", Region::Americas),
+    ("HR", "Croatia", Region::Europe),
+    ("HT", "Haiti", Region::Americas),
+    ("HU", "Hungary", Region::Europe),
+    ("ID", "Indonesia", Region::Asia),
+    ("IE", "Ireland", Region::Europe),
+    ("IL", "Israel", Region::Asia),
+    ("IM", "Isle of Man", Region::Europe),
+    ("IN", "India", Region::Asia),
+    ("IO", "British Indian Ocean Territory", Region::Africa),
+    ("IQ", "Iraq", Region::Asia),
+    ("IR", "Iran", Region::Asia),
+    ("IS", "Iceland", Region::Europe),
+    ("IT", "Italy", Region::Europe),
+    ("JE", "Jersey", Region::Europe),
+    ("JM", "Jamaica", Region::Americas),
+    ("JO", "Jordan", Region::Asia),
+    ("JP", "Japan", Region::Asia),
+    ("KE", "Kenya", Region::Africa),
+    ("KG", "Kyrgyzstan", Region::Asia),
+    ("KH", "Cambodia", Region::Asia),
+    ("KI", "Kiribati", Region::Oceania),
+    ("KM", "Comoros", Region::Africa),
+    ("KN", "Saint Kitts and Nevis", Region::Americas),
+    ("KP", "North Korea", Region::Asia),
+    ("KR", "South Korea", Region::Asia),
+    ("KW", "Kuwait", Region::Asia),
+    ("KY", "Cayman Islands", Region::Americas),
+    ("KZ", "Kazakhstan", Region::Asia),
+    ("LA", "Laos", Region::Asia),
+    ("LB", "Lebanon", Region::Asia),
+    ("LC", "Saint Lucia", Region::Americas),
+    ("LI", "Liechtenstein", Region::Europe),
+    ("LK", "Sri Lanka", Region::Asia),
+    ("LR", "Liberia", Region::Africa),
+    ("LS", "Lesotho", Region::Africa),
+    ("LT", "Lithuania", Region::Europe),
+    ("LU", "Luxembourg", Region::Europe),
+    ("LV", "Latvia", Region::Europe),
+    ("LY", "Libya", Region::Africa),
+    ("MA", "Morocco", Region::Africa),
+    ("MC", "Monaco", Region::Europe),
+    ("MD", "Moldova", Region::Europe),
+    ("ME", "Montenegro", Region::Europe),
+    ("MG", "Madagascar", Region::Africa),
+    ("MH", "Marshall Islands", Region::Oceania),
+    ("MK", "North Macedonia", Region::Europe),
+    ("ML", "Mali", Region::Africa),
+    ("MM", "Myanmar", Region::Asia),
+    ("MN", "Mongolia", Region::Asia),
+    ("MO", "Macao", Region::Asia),
+    ("MP", "Northern Mariana Islands", Region::Oceania),
+    ("MQ", "Martinique", Region::Americas),
+    ("MR", "Mauritania", Region::Africa),
+    ("MS", "Montserrat", Region::Americas),
+    ("MT", "Malta", Region::Europe),
+    ("MU", "Mauritius", Region::Africa),
+    ("MV", "Maldives", Region::Asia),
+    ("MW", "Malawi", Region::Africa),
+    ("MX", "Mexico", Region::Americas),
+    ("MY", "Malaysia", Region::Asia),
+    ("MZ", "Mozambique", Region::Africa),
+    ("NA", "Namibia", Region::Africa),
+    ("NC", "New Caledonia", Region::Oceania),
+    ("NE", "Niger", Region::Africa),
+    ("NF", "Norfolk Island", Region::Oceania),
+    ("NG", "Nigeria", Region::Africa),
+    ("NI", "Nicaragua", Region::Americas),
+    ("NL", "Netherlands", Region::Europe),
+    ("NO", "Norway", Region::Europe),
+    ("NP", "Nepal", Region::Asia),
+    ("NR", "Nauru", Region::Oceania),
+    ("NU", "Niue", Region::Oceania),
+    ("NZ", "New Zealand", Region::Oceania),
+    ("OM", "Oman", Region::Asia),
+    ("PA", "Panama", Region::Americas),
+    ("PE", "Peru", Region::Americas),
+    ("PF", "French Polynesia", Region::Oceania),
+    ("PG", "Papua New Guinea", Region::Oceania),
+    ("PH", "Philippines", Region::Asia),
+    ("PK", "Pakistan", Region::Asia),
+    ("PL", "Poland", Region::Europe),
+    ("PM", "Saint Pierre and Miquelon", Region::Americas),
+    ("PN", "Pitcairn", Region::Oceania),
+    ("PR", "Puerto Rico", Region::Americas),
+    ("PS", "Palestine", Region::Asia),
+    ("PT", "Portugal", Region::Europe),
+    ("PW", "Palau", Region::Oceania),
+    ("PY", "Paraguay", Region::Americas),
+    ("QA", "Qatar", Region::Asia),
+    ("RE", "Réunion", Region::Africa),
+    ("RO", "Romania", Region::Europe),
+    ("RS", "Serbia", Region::Europe),
+    ("RU", "Russia", Region::Europe),
+    ("RW", "Rwanda", Region::Africa),
+    ("SA", "Saudi Arabia", Region::Asia),
+    ("SB", "Solomon Islands", Region::Oceania),
+    ("SC", "Seychelles", Region::Africa),
+    ("SD", "Sudan", Region::Africa),
+    ("SE", "Sweden", Region::Europe),
+    ("SG", "Singapore", Region::Asia),
+    ("SI", "Slovenia", Region::Europe),
+    ("SJ", "Svalbard and Jan Mayen", Region::Europe),
+    ("SK", "Slovakia", Region::Europe),
+    ("SL", "Sierra Leone", Region::Africa),
+    ("SN", "Senegal", Region::Africa),
+    ("SO", "Somalia", Region::Africa),
+    ("SR", "Suriname", Region::Americas),
+    ("SS", "South Sudan", Region::Africa),
+    ("ST", "Sao Tome and Principe", Region::Africa),
+    ("SV", "El Salvador", Region::Americas),
+    ("SX", "Sint Maarten", Region::Americas),
+    ("SY", "Syria", Region::Asia),
+    ("SZ", "Eswatini", Region::Africa),
+    ("TC", "Turks and Caicos Islands", Region::Americas),
+    ("TD", "Chad", Region::Africa),
+    ("TG", "Togo", Region::Africa),
+    ("TH", "Thailand", Region::Asia),
+    ("TJ", "Tajikistan", Region::Asia),
+    ("TK", "Tokelau", Region::Oceania),
+    ("TL", "Timor-Leste", Region::Asia),
+    ("TM", "Turkmenistan", Region::Asia),
+    ("TN", "Tunisia", Region::Africa),
+    ("TO", "Tonga", Region::Oceania),
+    ("TR", "Turkey", Region::Asia),
+    ("TT", "Trinidad and Tobago", Region::Americas),
+    ("TV", "Tuvalu", Region::Oceania),
+    ("TW", "Taiwan", Region::Asia),
+    ("TZ", "Tanzania", Region::Africa),
+    ("UA", "Ukraine", Region::Europe),
+    ("UG", "Uganda", Region::Africa),
+    ("UK", "United Kingdom", Region::Europe),
+    ("US", "United States", Region::Americas),
+    ("UY", "Uruguay", Region::Americas),
+    ("UZ", "Uzbekistan", Region::Asia),
+    ("VA", "Holy See", Region::Europe),
+    ("VC", "Saint Vincent and the Grenadines", Region::Americas),
+    ("VE", "Venezuela", Region::Americas),
+    ("VG", "British Virgin Islands", Region::Americas),
+    ("VI", "United States Virgin Islands", Region::Americas),
+    ("VN", "Viet Nam", Region::Asia),
+    ("VU", "Vanuatu", Region::Oceania),
+    ("WF", "Wallis and Futuna", Region::Oceania),
+    ("WS", "Samoa", Region::Oceania),
+    ("XK", "Kosovo", Region::Europe),
+    ("YE", "Yemen", Region::Asia),
+    ("YT", "Mayotte", Region::Africa),
+    ("ZA", "South Africa", Region::Africa),
+    ("ZM", "Zambia", Region::Africa),
+    ("ZW", "Zimbabwe", Region::Africa),
+];
+
+/// Returns the English name of a country from its Alpha2 code, if it uses a
+/// currency defined by ISO 4217.
+#[cfg(feature = "alloc")]
+pub fn name(alpha2: &str) -> Option<&'static str> {
+    find(alpha2).map(|&(_, name, _)| name)
+}
+
+/// Returns the region of a country from its Alpha2 code, or the code of one
+/// of its subdivisions such as `SH-AC`.
+pub fn region(code: &str) -> Option<Region> {
+    find(code.get(..2)?).map(|&(_, _, region)| region)
+}
+
+fn find(alpha2: &str) -> Option<&'static (&'static str, &'static str, Region)> {
+    COUNTRIES
+        .binary_search_by(|&(code, _, _)| code.cmp(alpha2))
+        .ok()
+        .map(|idx| &COUNTRIES[idx])
+}
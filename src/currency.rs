@@ -0,0 +1,195 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use {CurrencyCode, alpha3, index, withdrawn};
+
+/// Currencies are identified by their Alpha3 code, which is unique, as is
+/// their numeric code.
+impl PartialEq for CurrencyCode {
+    fn eq(&self, other: &CurrencyCode) -> bool {
+        self.alpha3 == other.alpha3
+    }
+}
+
+impl Eq for CurrencyCode {}
+
+impl Hash for CurrencyCode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.alpha3.hash(state);
+    }
+}
+
+/// Currencies are ordered by Alpha3 code.
+impl Ord for CurrencyCode {
+    fn cmp(&self, other: &CurrencyCode) -> Ordering {
+        self.alpha3.cmp(other.alpha3)
+    }
+}
+
+impl PartialOrd for CurrencyCode {
+    fn partial_cmp(&self, other: &CurrencyCode) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Prints the Alpha3 code, or the name with the alternate flag: `EUR` for
+/// `{}` and `Euro` for `{:#}`.
+impl Display for CurrencyCode {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if f.alternate() {
+            f.write_str(self.name)
+        } else {
+            f.write_str(self.alpha3)
+        }
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = ParseCurrencyError;
+
+    /// Parses an Alpha3 code, as in `"EUR".parse::<CurrencyCode>()`.
+    fn from_str(s: &str) -> Result<CurrencyCode, ParseCurrencyError> {
+        <&CurrencyCode>::try_from(s).cloned()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'static CurrencyCode {
+    type Error = ParseCurrencyError;
+
+    /// Returns the currency with the given Alpha3 code, which must be three
+    /// uppercase ASCII letters.
+    fn try_from(code: &'a str) -> Result<&'static CurrencyCode, ParseCurrencyError> {
+        if code.is_empty() {
+            return Err(ParseCurrencyError::Empty);
+        }
+
+        let length = code.chars().count();
+
+        if length != 3 {
+            return Err(ParseCurrencyError::InvalidLength(length));
+        }
+
+        if let Some((position, found)) = code.chars().enumerate().find(|&(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseCurrencyError::InvalidCharacter { position, found });
+        }
+
+        if let Some(currency) = alpha3(code) {
+            return Ok(currency);
+        }
+
+        if let Some((currency, successor)) = withdrawn::alpha3(code) {
+            return Err(ParseCurrencyError::Withdrawn { currency, replaced_by: alpha3(successor) });
+        }
+
+        Err(ParseCurrencyError::Unknown { suggestion: index::nearest_alpha3(code.as_bytes()) })
+    }
+}
+
+impl TryFrom<u16> for &'static CurrencyCode {
+    type Error = ParseCurrencyError;
+
+    /// Returns the currency with the given numeric code.
+    fn try_from(num: u16) -> Result<&'static CurrencyCode, ParseCurrencyError> {
+        if let Some(currency) = index::num_value(num) {
+            return Ok(currency);
+        }
+
+        let digits = [b'0' + (num / 100 % 10) as u8, b'0' + (num / 10 % 10) as u8, b'0' + (num % 10) as u8];
+        let digits = core::str::from_utf8(&digits).unwrap_or("");
+
+        match withdrawn::num(digits) {
+            Some((currency, successor)) if num < 1000 => {
+                Err(ParseCurrencyError::Withdrawn { currency, replaced_by: alpha3(successor) })
+            },
+            _ => Err(ParseCurrencyError::Unknown { suggestion: index::nearest_num(num) }),
+        }
+    }
+}
+
+/// An error returned when a string or number is not the code of a current
+/// currency.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseCurrencyError {
+    /// The code is empty.
+    Empty,
+    /// The code is not three characters long; its length is given.
+    InvalidLength(usize),
+    /// The code holds a character other than an ASCII letter.
+    InvalidCharacter {
+        /// Position of the character, counted in characters
+        position: usize,
+        /// The character found
+        found: char,
+    },
+    /// The code is well-formed but not assigned to any currency. The closest
+    /// known code is suggested if one is close enough.
+    Unknown {
+        /// The currency with the closest code, such as EUR for `EUD`
+        suggestion: Option<&'static CurrencyCode>,
+    },
+    /// The code belonged to a currency that has been withdrawn.
+    Withdrawn {
+        /// The withdrawn currency
+        currency: &'static CurrencyCode,
+        /// The currency that replaced it
+        replaced_by: Option<&'static CurrencyCode>,
+    },
+}
+
+impl Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            ParseCurrencyError::Empty => f.write_str("empty currency code"),
+            ParseCurrencyError::InvalidLength(length) => {
+                write!(f, "currency codes have 3 characters, found {}", length)
+            },
+            ParseCurrencyError::InvalidCharacter { position, found } => {
+                write!(f, "invalid character {:?} at position {} in currency code", found, position)
+            },
+            ParseCurrencyError::Unknown { suggestion: Some(currency) } => {
+                write!(f, "unknown currency code, did you mean {} ({:#})?", currency, currency)
+            },
+            ParseCurrencyError::Unknown { suggestion: None } => f.write_str("unknown currency code"),
+            ParseCurrencyError::Withdrawn { currency, replaced_by } => {
+                write!(f, "{} ({:#}) has been withdrawn", currency, currency)?;
+
+                match replaced_by {
+                    Some(currency) => write!(f, ", replaced by {} ({:#})", currency, currency),
+                    None => Ok(()),
+                }
+            },
+        }
+    }
+}
+
+impl Error for ParseCurrencyError {}
@@ -0,0 +1,257 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+//! Readers for the euro foreign exchange reference rates published by the
+//! European Central Bank.
+//!
+//! Both the XML format of `eurofxref-daily.xml` and the CSV format of
+//! `eurofxref-hist.csv` are supported. Every rate read has EUR as its base
+//! currency and is paired with the date it was published for, ready to be
+//! inserted into a [`RateStore`](../struct.RateStore.html).
+//!
+//! This module is only available with the `ecb` feature.
+
+use std::borrow::ToOwned;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::string::String;
+use std::vec::Vec;
+
+use {CurrencyCode, Date, ExchangeRate, alpha3};
+
+/// An error returned when reading ECB reference rates fails.
+#[derive(Debug)]
+pub enum EcbError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The input is not in the expected format.
+    Malformed {
+        /// 1-based line of the input the problem was found on
+        line: usize,
+        /// Description of the problem
+        reason: &'static str,
+    },
+    /// A rate was given for a currency code that is not in this crate's data.
+    UnknownCurrency {
+        /// 1-based line of the input the code was found on
+        line: usize,
+        /// The unknown code
+        code: String,
+    },
+}
+
+impl Display for EcbError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            EcbError::Io(ref err) => write!(f, "failed to read rates: {}", err),
+            EcbError::Malformed { line, reason } => write!(f, "line {}: {}", line, reason),
+            EcbError::UnknownCurrency { line, ref code } => {
+                write!(f, "line {}: unknown currency code {:?}", line, code)
+            },
+        }
+    }
+}
+
+impl Error for EcbError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            EcbError::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for EcbError {
+    fn from(err: io::Error) -> EcbError {
+        EcbError::Io(err)
+    }
+}
+
+/// A reader for ECB reference rate files.
+///
+/// By default a rate for a currency that is not in this crate's data is an
+/// error. The historical file still lists currencies replaced by the euro,
+/// such as CYP, so [`ignore_unknown`](#method.ignore_unknown) can be used to
+/// skip those instead.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Parser {
+    ignore_unknown: bool,
+}
+
+impl Parser {
+    /// Creates a parser that reports unknown currency codes as errors.
+    pub fn new() -> Parser {
+        Parser::default()
+    }
+
+    /// Sets whether rates for unknown currency codes are skipped rather than
+    /// reported.
+    pub fn ignore_unknown(mut self, ignore: bool) -> Parser {
+        self.ignore_unknown = ignore;
+        self
+    }
+
+    /// Reads rates from a file in the `eurofxref-daily.xml` format.
+    pub fn load_xml<P: AsRef<Path>>(&self, path: P) -> Result<Vec<(Date, ExchangeRate)>, EcbError> {
+        self.parse_xml(&fs::read_to_string(path)?)
+    }
+
+    /// Reads rates from a file in the `eurofxref-hist.csv` format.
+    pub fn load_csv<P: AsRef<Path>>(&self, path: P) -> Result<Vec<(Date, ExchangeRate)>, EcbError> {
+        self.parse_csv(&fs::read_to_string(path)?)
+    }
+
+    /// Parses rates from a document in the `eurofxref-daily.xml` format.
+    ///
+    /// Rates are read from `<Cube currency='…' rate='…'/>` elements nested in
+    /// a `<Cube time='…'>` element, so files with several days are supported.
+    pub fn parse_xml(&self, input: &str) -> Result<Vec<(Date, ExchangeRate)>, EcbError> {
+        let eur = euro();
+        let mut date = None;
+        let mut rates = Vec::new();
+        let mut rest = input;
+
+        while let Some(start) = rest.find("<Cube") {
+            let offset = input.len() - rest.len() + start;
+            let line = input[..offset].matches('\n').count() + 1;
+            let tag = &rest[start..];
+            let end = tag.find('>').ok_or(EcbError::Malformed { line, reason: "unterminated Cube element" })?;
+            let tag = &tag[..end];
+            rest = &rest[start + end..];
+
+            if let Some(time) = attribute(tag, "time") {
+                date = Some(time.parse().map_err(|_| EcbError::Malformed { line, reason: "invalid date" })?);
+            }
+
+            let code = match attribute(tag, "currency") {
+                Some(code) => code,
+                None => continue,
+            };
+            let date = date.ok_or(EcbError::Malformed { line, reason: "rate outside of a dated Cube" })?;
+            let rate = attribute(tag, "rate").ok_or(EcbError::Malformed { line, reason: "missing rate" })?;
+
+            if let Some(rate) = self.rate(eur, code, rate, line)? {
+                rates.push((date, rate));
+            }
+        }
+
+        Ok(rates)
+    }
+
+    /// Parses rates from a document in the `eurofxref-hist.csv` format.
+    ///
+    /// The header names the currency of each column after the leading `Date`
+    /// column. Cells containing `N/A` or nothing are skipped.
+    pub fn parse_csv(&self, input: &str) -> Result<Vec<(Date, ExchangeRate)>, EcbError> {
+        let eur = euro();
+        let mut lines = input.lines().enumerate().map(|(idx, line)| (idx + 1, line));
+        let header = match lines.next() {
+            Some((_, header)) => header,
+            None => return Ok(Vec::new()),
+        };
+        let mut columns = header.split(',').map(str::trim);
+
+        if columns.next() != Some("Date") {
+            return Err(EcbError::Malformed { line: 1, reason: "missing Date column" });
+        }
+
+        let codes = columns.collect::<Vec<_>>();
+        let mut rates = Vec::new();
+
+        for (line, row) in lines.filter(|&(_, row)| !row.trim().is_empty()) {
+            let mut cells = row.split(',').map(str::trim);
+            let date = cells
+                .next()
+                .and_then(|date| date.parse::<Date>().ok())
+                .ok_or(EcbError::Malformed { line, reason: "invalid date" })?;
+
+            for (code, cell) in codes.iter().zip(cells) {
+                if cell.is_empty() || cell == "N/A" {
+                    continue;
+                }
+
+                if let Some(rate) = self.rate(eur, code, cell, line)? {
+                    rates.push((date, rate));
+                }
+            }
+        }
+
+        Ok(rates)
+    }
+
+    fn rate(&self, eur: &'static CurrencyCode, code: &str, rate: &str, line: usize) -> Result<Option<ExchangeRate>, EcbError> {
+        let quote = match alpha3(code) {
+            Some(quote) => quote,
+            None if self.ignore_unknown => return Ok(None),
+            None => return Err(EcbError::UnknownCurrency { line, code: code.to_owned() }),
+        };
+        let rate = rate.parse().map_err(|_| EcbError::Malformed { line, reason: "invalid rate" })?;
+
+        Ok(Some(ExchangeRate::new(eur, quote, rate)))
+    }
+}
+
+/// Reads rates from a file in the `eurofxref-daily.xml` format, reporting
+/// unknown currency codes as errors.
+pub fn load_xml<P: AsRef<Path>>(path: P) -> Result<Vec<(Date, ExchangeRate)>, EcbError> {
+    Parser::new().load_xml(path)
+}
+
+/// Reads rates from a file in the `eurofxref-hist.csv` format, reporting
+/// unknown currency codes as errors.
+pub fn load_csv<P: AsRef<Path>>(path: P) -> Result<Vec<(Date, ExchangeRate)>, EcbError> {
+    Parser::new().load_csv(path)
+}
+
+fn euro() -> &'static CurrencyCode {
+    alpha3("EUR").expect("EUR is an ISO 4217 currency")
+}
+
+/// Returns the value of an XML attribute in a tag, quoted with `'` or `"`.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+
+    while let Some(idx) = rest.find(name) {
+        let preceded = rest[..idx].ends_with(char::is_whitespace);
+        let after = rest[idx + name.len()..].trim_start();
+        rest = &rest[idx + name.len()..];
+
+        if !preceded || !after.starts_with('=') {
+            continue;
+        }
+
+        let value = after[1..].trim_start();
+        let quote = value.chars().next().filter(|&c| c == '\'' || c == '"')?;
+        let value = &value[1..];
+
+        return value.find(quote).map(|end| &value[..end]);
+    }
+
+    None
+}
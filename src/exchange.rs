@@ -0,0 +1,259 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::FromStr;
+
+use money::div_round;
+use {CurrencyCode, Money, RoundingMode};
+
+/// A positive exchange rate, stored exactly as a reduced fraction.
+///
+/// Rates parsed from decimal strings such as `"1.0856"` keep every digit, and
+/// inverting or crossing rates never loses precision.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rate {
+    numerator: u128,
+    denominator: u128,
+}
+
+impl Rate {
+    /// Creates the rate `numerator / denominator`, if both are non-zero.
+    pub fn new(numerator: u128, denominator: u128) -> Option<Rate> {
+        if numerator == 0 || denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator);
+
+        Some(Rate {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    /// Creates the rate `mantissa / 10^scale`, e.g. `from_decimal(10856, 4)`
+    /// for 1.0856.
+    pub fn from_decimal(mantissa: u128, scale: u32) -> Option<Rate> {
+        Rate::new(mantissa, 10u128.checked_pow(scale)?)
+    }
+
+    /// Returns the numerator of the reduced fraction.
+    pub fn numerator(&self) -> u128 {
+        self.numerator
+    }
+
+    /// Returns the denominator of the reduced fraction.
+    pub fn denominator(&self) -> u128 {
+        self.denominator
+    }
+
+    /// Returns the reciprocal of the rate.
+    pub fn inverse(&self) -> Rate {
+        Rate {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+
+    /// Multiplies two rates, returning `None` on overflow.
+    pub fn checked_mul(&self, other: &Rate) -> Option<Rate> {
+        // Cross-reduce first to keep the intermediate values small.
+        let a = gcd(self.numerator, other.denominator);
+        let b = gcd(other.numerator, self.denominator);
+
+        Some(Rate {
+            numerator: (self.numerator / a).checked_mul(other.numerator / b)?,
+            denominator: (self.denominator / b).checked_mul(other.denominator / a)?,
+        })
+    }
+}
+
+impl FromStr for Rate {
+    type Err = ParseRateError;
+
+    fn from_str(s: &str) -> Result<Rate, ParseRateError> {
+        let (whole, fraction) = match s.find('.') {
+            Some(idx) => (&s[..idx], &s[idx + 1..]),
+            None => (s, ""),
+        };
+        let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+
+        if whole.is_empty() && fraction.is_empty() || !digits(whole) || !digits(fraction) {
+            return Err(ParseRateError::Invalid);
+        }
+
+        let mut mantissa = 0u128;
+
+        for b in whole.bytes().chain(fraction.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(u128::from(b - b'0')))
+                .ok_or(ParseRateError::Overflow)?;
+        }
+
+        let scale = u32::try_from(fraction.len()).map_err(|_| ParseRateError::Overflow)?;
+
+        if mantissa == 0 {
+            return Err(ParseRateError::Zero);
+        }
+
+        Rate::from_decimal(mantissa, scale).ok_or(ParseRateError::Overflow)
+    }
+}
+
+/// An error returned when parsing a `Rate` from a decimal string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseRateError {
+    /// The string is not a plain decimal number such as `1.0856`.
+    Invalid,
+    /// The number has too many digits to be stored exactly.
+    Overflow,
+    /// The rate is zero.
+    Zero,
+}
+
+impl Display for ParseRateError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            ParseRateError::Invalid => "invalid decimal rate",
+            ParseRateError::Overflow => "rate has too many digits",
+            ParseRateError::Zero => "rate is zero",
+        })
+    }
+}
+
+impl Error for ParseRateError {}
+
+/// The price of one unit of the `base` currency in units of the `quote`
+/// currency, e.g. EUR/USD at 1.0856.
+///
+/// Rates are expressed in major units; the exponents of both currencies are
+/// applied when converting amounts.
+#[derive(Clone, Copy, Debug)]
+pub struct ExchangeRate {
+    /// Currency being priced
+    pub base: &'static CurrencyCode,
+    /// Currency the price is expressed in
+    pub quote: &'static CurrencyCode,
+    /// Units of `quote` per unit of `base`
+    pub rate: Rate,
+}
+
+impl ExchangeRate {
+    /// Creates an exchange rate between two currencies.
+    pub fn new(base: &'static CurrencyCode, quote: &'static CurrencyCode, rate: Rate) -> ExchangeRate {
+        ExchangeRate { base, quote, rate }
+    }
+
+    /// Returns the rate in the opposite direction, from `quote` to `base`.
+    pub fn inverse(&self) -> ExchangeRate {
+        ExchangeRate::new(self.quote, self.base, self.rate.inverse())
+    }
+
+    /// Derives a cross rate from two rates sharing a pivot currency.
+    ///
+    /// Given EUR/USD and JPY/USD, this returns EUR/JPY: the base is the
+    /// currency of `self` that is not the pivot and the quote is the one of
+    /// `other`. Either rate may be quoted in either direction.
+    ///
+    /// Returns `None` if the rates do not share exactly one currency or the
+    /// result overflows.
+    pub fn cross(&self, other: &ExchangeRate) -> Option<ExchangeRate> {
+        let pivot = if same(self.quote, other.base) || same(self.quote, other.quote) {
+            self.quote
+        } else if same(self.base, other.base) || same(self.base, other.quote) {
+            self.base
+        } else {
+            return None;
+        };
+
+        // Orient both rates as X/pivot and pivot/Y.
+        let left = if same(self.quote, pivot) { *self } else { self.inverse() };
+        let right = if same(other.base, pivot) { *other } else { other.inverse() };
+
+        if same(left.base, right.quote) {
+            return None;
+        }
+
+        let rate = left.rate.checked_mul(&right.rate)?;
+
+        Some(ExchangeRate::new(left.base, right.quote, rate))
+    }
+
+    /// Converts an amount in either currency of the rate into the other one.
+    ///
+    /// See [`convert`](fn.convert.html).
+    pub fn convert(&self, money: Money, mode: RoundingMode) -> Option<Money> {
+        convert(money, self, mode)
+    }
+}
+
+/// Converts an amount into the other currency of an exchange rate, rounding
+/// to the minor unit of the target currency.
+///
+/// The amount may be in either the base or the quote currency of the rate.
+/// Returns `None` if it is in neither or the result does not fit in an `i64`.
+pub fn convert(money: Money, rate: &ExchangeRate, mode: RoundingMode) -> Option<Money> {
+    let rate = if same(money.currency, rate.base) {
+        *rate
+    } else if same(money.currency, rate.quote) {
+        rate.inverse()
+    } else {
+        return None;
+    };
+
+    let shift = i32::from(rate.quote.exp) - i32::from(rate.base.exp);
+    let scale = 10i128.checked_pow(shift.unsigned_abs())?;
+    let mut numerator = i128::try_from(rate.rate.numerator()).ok()?.checked_mul(i128::from(money.amount))?;
+    let mut denominator = i128::try_from(rate.rate.denominator()).ok()?;
+
+    if shift >= 0 {
+        numerator = numerator.checked_mul(scale)?;
+    } else {
+        denominator = denominator.checked_mul(scale)?;
+    }
+
+    let amount = div_round(numerator, denominator, mode)?;
+
+    i64::try_from(amount).ok().map(|amount| Money::new(amount, rate.quote))
+}
+
+fn same(a: &CurrencyCode, b: &CurrencyCode) -> bool {
+    a.alpha3 == b.alpha3
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+
+    a
+}
@@ -0,0 +1,579 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use locales;
+use money::div_round;
+use {CurrencyCode, Money, RoundingMode};
+
+/// How the currency of an amount is shown when formatting it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CurrencyDisplay {
+    /// The Alpha3 code, e.g. `USD 1,234.56`.
+    Code,
+    /// The locale's symbol, e.g. `$1,234.56`, falling back to the code for
+    /// currencies without one.
+    Symbol,
+    /// The name of the currency, e.g. `1,234.56 United States dollar`.
+    Name,
+}
+
+/// How the number of a formatted amount is written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Notation {
+    /// The full amount, e.g. `$1,234,567.89`.
+    Standard,
+    /// Abbreviated with a short suffix, e.g. `$1.2M` or `1,2 Mio. €`.
+    CompactShort,
+    /// Abbreviated with a word, e.g. `$1.2 million` or `1,2 Millionen €`.
+    CompactLong,
+}
+
+/// A set of digits used to write numbers, named after its CLDR identifier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NumberingSystem {
+    /// Latin digits `0123456789` (`latn`).
+    Latn,
+    /// Arabic-Indic digits `٠١٢٣٤٥٦٧٨٩` (`arab`).
+    Arab,
+    /// Extended Arabic-Indic digits `۰۱۲۳۴۵۶۷۸۹` (`arabext`).
+    ArabExt,
+    /// Bengali digits `০১২৩৪৫৬৭৮৯` (`beng`).
+    Beng,
+    /// Devanagari digits `०१२३४५६७८९` (`deva`).
+    Deva,
+}
+
+impl NumberingSystem {
+    /// Returns the numbering system with the given CLDR identifier, such as
+    /// `deva`.
+    pub fn from_id(id: &str) -> Option<NumberingSystem> {
+        Some(match &*id.to_ascii_lowercase() {
+            "latn" => NumberingSystem::Latn,
+            "arab" => NumberingSystem::Arab,
+            "arabext" => NumberingSystem::ArabExt,
+            "beng" => NumberingSystem::Beng,
+            "deva" => NumberingSystem::Deva,
+            _ => return None,
+        })
+    }
+
+    /// Returns the numbering system requested by the `-u-nu-` extension of a
+    /// language tag, e.g. `deva` for `hi-IN-u-nu-deva`.
+    pub fn from_tag(tag: &str) -> Option<NumberingSystem> {
+        let tag = tag.replace('_', "-").to_ascii_lowercase();
+        let extension = &tag[tag.find("-u-")? + 2..];
+        let mut subtags = extension.split('-');
+
+        subtags.position(|subtag| subtag == "nu")?;
+        subtags.next().and_then(NumberingSystem::from_id)
+    }
+
+    /// Returns the digits from zero to nine.
+    pub fn digits(&self) -> [char; 10] {
+        let zero = match *self {
+            NumberingSystem::Latn => '0',
+            NumberingSystem::Arab => '\u{660}',
+            NumberingSystem::ArabExt => '\u{6f0}',
+            NumberingSystem::Beng => '\u{9e6}',
+            NumberingSystem::Deva => '\u{966}',
+        };
+        let mut digits = [zero; 10];
+
+        for (value, digit) in digits.iter_mut().enumerate() {
+            // All supported systems have contiguous digits.
+            *digit = char::from_u32(zero as u32 + value as u32).unwrap_or(zero);
+        }
+
+        digits
+    }
+}
+
+/// The CLDR rule choosing between the `one` and `other` plural forms of a
+/// locale, as used by compact long patterns.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PluralRule {
+    /// Exactly 1 takes the `one` form, as in English `1 million` but
+    /// `1.2 million`.
+    ExactlyOne,
+    /// Exactly 1 and numbers below it take the `one` form, as in Hindi.
+    UpToOne,
+    /// Numbers below 2 take the `one` form, as in French `1,2 million`.
+    BelowTwo,
+    /// Every number takes the `other` form, as in Japanese.
+    Other,
+}
+
+impl PluralRule {
+    /// Returns whether a number with the given integer part, and with or
+    /// without fraction digits, takes the `one` form.
+    fn is_one(&self, integer: i128, fraction: bool) -> bool {
+        match *self {
+            PluralRule::ExactlyOne => integer == 1 && !fraction,
+            PluralRule::UpToOne => integer == 0 || (integer == 1 && !fraction),
+            PluralRule::BelowTwo => integer < 2,
+            PluralRule::Other => false,
+        }
+    }
+}
+
+/// Number and currency formatting conventions of a locale, taken from CLDR.
+///
+/// Patterns use the CLDR syntax: `#` and `0` are digits, `,` and `.` mark the
+/// grouping and decimal separators, `¤` is the currency and `-` the minus
+/// sign. A pattern may hold a negative subpattern after a `;`. Grouping
+/// sizes come from the pattern too, so `#,##,##0.00` groups the last three
+/// digits and every two before them, as in `12,34,567.89`. The number of
+/// fraction digits always comes from the currency's `exp`, not the pattern.
+#[derive(Clone, Debug)]
+pub struct Locale {
+    /// BCP 47 language tag, e.g. `de-CH`
+    pub tag: &'static str,
+    /// Decimal separator
+    pub decimal: &'static str,
+    /// Grouping separator
+    pub group: &'static str,
+    /// Minus sign
+    pub minus: &'static str,
+    /// Digits used by default
+    pub numbering: NumberingSystem,
+    /// Minimum number of digits in front of the first grouping separator
+    /// for grouping to be applied
+    pub min_grouping: u8,
+    /// Standard currency pattern
+    pub pattern: &'static str,
+    /// Accounting currency pattern
+    pub accounting_pattern: &'static str,
+    /// Compact patterns for abbreviated amounts, as pairs of the power of ten
+    /// they start at and the pattern, e.g. `(6, "¤0M")`
+    pub compact_short: &'static [(u8, &'static str)],
+    /// Compact number patterns spelling out the magnitude, as the power of
+    /// ten they start at and the patterns for the `one` and `other` plural
+    /// forms, e.g. `(6, "0 Million", "0 Millionen")`
+    pub compact_long: &'static [(u8, &'static str, &'static str)],
+    /// Rule choosing the plural form of compact long patterns
+    pub plural: PluralRule,
+    /// Currency symbols that differ from the default ones in this locale,
+    /// as pairs of Alpha3 code and symbol
+    pub symbols: &'static [(&'static str, &'static str)],
+}
+
+impl Locale {
+    /// Returns all supported locales.
+    pub fn all() -> &'static [Locale] {
+        locales::all()
+    }
+
+    /// Returns the locale with the given tag, if one exists.
+    ///
+    /// Tags are matched case-insensitively and may use `_` instead of `-`.
+    /// A tag with an unsupported region falls back to its language, so
+    /// `de-LU` returns the locale for `de`. Unicode extensions such as
+    /// `-u-nu-deva` are ignored.
+    pub fn get(tag: &str) -> Option<&'static Locale> {
+        let mut tag = tag.replace('_', "-");
+
+        if let Some(idx) = tag.to_ascii_lowercase().find("-u-") {
+            tag.truncate(idx);
+        }
+
+        let find = |tag: &str| locales::all().iter().find(|l| l.tag.eq_ignore_ascii_case(tag));
+
+        find(&tag).or_else(|| tag.split('-').next().and_then(find))
+    }
+
+    /// Returns the symbol of a currency in this locale, or its Alpha3 code if
+    /// it has no symbol.
+    pub fn symbol(&self, currency: &CurrencyCode) -> &'static str {
+        self.symbols
+            .iter()
+            .chain(SYMBOLS.iter())
+            .find(|&&(code, _)| code == currency.alpha3)
+            .map_or(currency.alpha3, |&(_, symbol)| symbol)
+    }
+}
+
+/// Formats amounts of money following the conventions of a locale.
+#[derive(Clone, Copy, Debug)]
+pub struct MoneyFormatter {
+    locale: &'static Locale,
+    display: CurrencyDisplay,
+    accounting: bool,
+    numbering: NumberingSystem,
+    notation: Notation,
+    significant_digits: u32,
+    rounding: RoundingMode,
+}
+
+impl MoneyFormatter {
+    /// Creates a formatter for a locale, showing currency symbols and
+    /// negative amounts with a minus sign.
+    pub fn new(locale: &'static Locale) -> MoneyFormatter {
+        MoneyFormatter {
+            locale,
+            display: CurrencyDisplay::Symbol,
+            accounting: false,
+            numbering: locale.numbering,
+            notation: Notation::Standard,
+            significant_digits: 2,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Creates a formatter for a language tag, honouring a numbering system
+    /// requested with a `-u-nu-` extension, as in `hi-IN-u-nu-deva`.
+    ///
+    /// Returns `None` if there is no locale for the tag.
+    pub fn from_tag(tag: &str) -> Option<MoneyFormatter> {
+        let formatter = MoneyFormatter::new(Locale::get(tag)?);
+
+        Some(match NumberingSystem::from_tag(tag) {
+            Some(numbering) => formatter.numbering(numbering),
+            None => formatter,
+        })
+    }
+
+    /// Sets how the currency is shown.
+    pub fn display(mut self, display: CurrencyDisplay) -> MoneyFormatter {
+        self.display = display;
+        self
+    }
+
+    /// Sets whether the accounting pattern is used, which shows negative
+    /// amounts in parentheses in many locales.
+    pub fn accounting(mut self, accounting: bool) -> MoneyFormatter {
+        self.accounting = accounting;
+        self
+    }
+
+    /// Sets the digits used, overriding the locale's default. Separators and
+    /// symbols still come from the locale.
+    pub fn numbering(mut self, numbering: NumberingSystem) -> MoneyFormatter {
+        self.numbering = numbering;
+        self
+    }
+
+    /// Sets how the number is written.
+    ///
+    /// Compact notations fall back to the standard one for amounts too small
+    /// to abbreviate and for locales without compact patterns. When a locale
+    /// has no long patterns, the short ones are used instead.
+    pub fn notation(mut self, notation: Notation) -> MoneyFormatter {
+        self.notation = notation;
+        self
+    }
+
+    /// Sets the minimum number of significant digits shown in compact
+    /// notations, 2 by default. Integer digits are never dropped, so
+    /// 123,456 is shown as `$123K` rather than `$120K`.
+    ///
+    /// The number is clamped between 1 and 18, which keeps every `i64`
+    /// amount in range while rounding.
+    pub fn significant_digits(mut self, digits: u32) -> MoneyFormatter {
+        self.significant_digits = digits.clamp(1, 18);
+        self
+    }
+
+    /// Sets how compact amounts are rounded, half to even by default.
+    pub fn rounding(mut self, rounding: RoundingMode) -> MoneyFormatter {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Formats an amount given in minor units of a currency.
+    pub fn format(&self, amount: i64, currency: &CurrencyCode) -> String {
+        let locale = self.locale;
+        let standard = if self.accounting { locale.accounting_pattern } else { locale.pattern };
+        let short = locale.compact_short.iter().map(|&(key, _)| key).collect::<Vec<_>>();
+        let long = locale.compact_long.iter().map(|&(key, _, _)| key).collect::<Vec<_>>();
+
+        let compact = match self.notation {
+            Notation::Standard => None,
+            Notation::CompactLong if !long.is_empty() => self.compact(amount, currency.exp, &long).map(|(idx, number, is_one)| {
+                let (_, one, other) = locale.compact_long[idx];
+                let words = Pattern::parse(if is_one { one } else { other }).positive;
+
+                (Pattern::parse(standard), format!("{}{}{}", words.prefix, number, words.suffix))
+            }),
+            Notation::CompactShort | Notation::CompactLong => {
+                self.compact(amount, currency.exp, &short).map(|(idx, number, _)| {
+                    (Pattern::parse(locale.compact_short[idx].1), number)
+                })
+            },
+        };
+        let (pattern, number) = compact.unwrap_or_else(|| {
+            let pattern = Pattern::parse(standard);
+            let number = pattern.number(amount.unsigned_abs(), currency.exp, locale, self.numbering);
+
+            (pattern, number)
+        });
+
+        self.write(amount < 0, &pattern, &number, currency)
+    }
+
+    /// Formats an amount of money.
+    pub fn format_money(&self, money: Money) -> String {
+        self.format(money.amount, money.currency)
+    }
+
+    /// Rounds an amount for a compact pattern, returning the index of the
+    /// pattern, the abbreviated number and whether it takes the `one` plural
+    /// form.
+    fn compact(&self, amount: i64, exp: i8, keys: &[u8]) -> Option<(usize, String, bool)> {
+        let exp = exp.max(0) as u32;
+        let amount = i128::from(amount.unsigned_abs());
+        let mut magnitude = (amount / 10i128.pow(exp)).checked_ilog10()?;
+
+        loop {
+            let idx = keys.iter().rposition(|&key| u32::from(key) <= magnitude)?;
+            let key = u32::from(keys[idx]);
+            let decimals = self.significant_digits.saturating_sub(magnitude - key + 1);
+            let rounded = div_round(amount * 10i128.pow(decimals), 10i128.pow(exp + key), self.rounding)?;
+            let rounded_magnitude = (rounded.checked_ilog10()? + key).saturating_sub(decimals);
+
+            // Rounding up may carry into the next magnitude, as 999,950
+            // becoming 1.0M rather than 1000K.
+            if rounded_magnitude > magnitude {
+                magnitude = rounded_magnitude;
+                continue;
+            }
+
+            let digits = format!("{:01$}", rounded, decimals as usize + 1);
+            let (integer, fraction) = digits.split_at(digits.len() - decimals as usize);
+            let fraction = fraction.trim_end_matches('0');
+            let numerals = self.numbering.digits();
+            let mut number = integer.chars().map(|c| numerals[c as usize - '0' as usize]).collect::<String>();
+
+            if !fraction.is_empty() {
+                number.push_str(self.locale.decimal);
+                number.extend(fraction.chars().map(|c| numerals[c as usize - '0' as usize]));
+            }
+
+            // The plural form depends on the value, whatever digits show it.
+            let is_one = self.locale.plural.is_one(rounded / 10i128.pow(decimals), !fraction.is_empty());
+
+            return Some((idx, number, is_one));
+        }
+    }
+
+    /// Writes a number into a pattern, adding the currency and sign.
+    fn write(&self, negative: bool, pattern: &Pattern, number: &str, currency: &CurrencyCode) -> String {
+        let locale = self.locale;
+        let affixes = match pattern.negative {
+            Some(ref negative_affixes) if negative => negative_affixes.clone(),
+            _ => pattern.positive.clone(),
+        };
+        let (prefix, suffix, currency_text) = match self.display {
+            CurrencyDisplay::Code => (affixes.prefix.to_owned(), affixes.suffix.to_owned(), currency.alpha3),
+            CurrencyDisplay::Symbol => (affixes.prefix.to_owned(), affixes.suffix.to_owned(), locale.symbol(currency)),
+            // The name is written after the number instead, so drop the
+            // currency and the space around it from the pattern.
+            CurrencyDisplay::Name => (strip_currency(affixes.prefix), strip_currency(affixes.suffix), ""),
+        };
+
+        let mut out = String::new();
+
+        if negative && pattern.negative.is_none() {
+            out.push_str(locale.minus);
+        }
+
+        expand(&mut out, &prefix, currency_text, locale, Side::Prefix);
+        out.push_str(number);
+        expand(&mut out, &suffix, currency_text, locale, Side::Suffix);
+
+        if self.display == CurrencyDisplay::Name {
+            out.push(' ');
+            out.push_str(currency.name);
+        }
+
+        out
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Prefix,
+    Suffix,
+}
+
+#[derive(Clone)]
+struct Affixes<'a> {
+    prefix: &'a str,
+    suffix: &'a str,
+}
+
+impl<'a> Affixes<'a> {
+    fn parse(pattern: &'a str) -> (Affixes<'a>, &'a str) {
+        let is_number = |c: char| "#0,.".contains(c);
+        let start = pattern.find(is_number).unwrap_or(pattern.len());
+        let len = pattern[start..].find(|c| !is_number(c)).unwrap_or(pattern.len() - start);
+        let affixes = Affixes {
+            prefix: &pattern[..start],
+            suffix: &pattern[start + len..],
+        };
+
+        (affixes, &pattern[start..start + len])
+    }
+}
+
+struct Pattern<'a> {
+    positive: Affixes<'a>,
+    negative: Option<Affixes<'a>>,
+    primary: usize,
+    secondary: usize,
+}
+
+impl<'a> Pattern<'a> {
+    fn parse(pattern: &'a str) -> Pattern<'a> {
+        let mut subpatterns = pattern.splitn(2, ';');
+        let (positive, number) = Affixes::parse(subpatterns.next().unwrap_or(""));
+        let negative = subpatterns.next().map(|negative| Affixes::parse(negative).0);
+        let integer = number.split('.').next().unwrap_or("");
+        let mut commas = integer.rmatch_indices(',').map(|(idx, _)| idx);
+        // The secondary size is at least one, so grouping can divide by it.
+        let (primary, secondary) = match (commas.next(), commas.next()) {
+            (Some(last), Some(previous)) => (integer.len() - last - 1, (last - previous - 1).max(1)),
+            (Some(last), None) => (integer.len() - last - 1, (integer.len() - last - 1).max(1)),
+            _ => (0, 1),
+        };
+
+        Pattern { positive, negative, primary, secondary }
+    }
+
+    /// Renders an absolute amount in minor units, with grouping and the
+    /// currency's number of decimals.
+    fn number(&self, amount: u64, exp: i8, locale: &Locale, numbering: NumberingSystem) -> String {
+        let exp = exp.max(0) as u32;
+        let scale = 10u64.pow(exp);
+        let integer = (amount / scale).to_string();
+        let fraction = format!("{:01$}", amount % scale, exp as usize);
+        let digits = numbering.digits();
+        let digit = |c: char| c.to_digit(10).map_or(c, |d| digits[d as usize]);
+        let mut out = String::new();
+        let grouped = self.primary > 0 && integer.len() >= self.primary + usize::from(locale.min_grouping);
+
+        for (idx, c) in integer.char_indices() {
+            let remaining = integer.len() - idx;
+
+            if grouped && idx > 0 && remaining >= self.primary && (remaining - self.primary) % self.secondary == 0 {
+                out.push_str(locale.group);
+            }
+
+            out.push(digit(c));
+        }
+
+        if exp > 0 {
+            out.push_str(locale.decimal);
+            out.extend(fraction.chars().map(digit));
+        }
+
+        out
+    }
+}
+
+/// Removes the currency placeholder and any whitespace next to it from an
+/// affix.
+fn strip_currency(affix: &str) -> String {
+    match affix.find('¤') {
+        Some(idx) => {
+            let before = affix[..idx].trim_end();
+            let after = affix[idx + '¤'.len_utf8()..].trim_start();
+
+            format!("{}{}", before, after)
+        },
+        None => affix.to_owned(),
+    }
+}
+
+/// Writes an affix, replacing `¤` with the currency and `-` with the minus
+/// sign.
+///
+/// Following CLDR's currency spacing rules, a no-break space separates the
+/// currency from the number when it is written next to it and ends in a
+/// letter, as in `USD 1.00`.
+fn expand(out: &mut String, affix: &str, currency: &str, locale: &Locale, side: Side) {
+    for (idx, c) in affix.char_indices() {
+        match c {
+            '¤' => {
+                let touches_number = match side {
+                    Side::Prefix => idx + c.len_utf8() == affix.len(),
+                    Side::Suffix => idx == 0,
+                };
+                let letter = match side {
+                    Side::Prefix => currency.chars().next_back(),
+                    Side::Suffix => currency.chars().next(),
+                }.is_some_and(char::is_alphabetic);
+
+                if touches_number && letter {
+                    if let Side::Suffix = side {
+                        out.push('\u{a0}');
+                    }
+
+                    out.push_str(currency);
+
+                    if let Side::Prefix = side {
+                        out.push('\u{a0}');
+                    }
+                } else {
+                    out.push_str(currency);
+                }
+            },
+            '-' => out.push_str(locale.minus),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Default currency symbols, used by locales that don't override them.
+const SYMBOLS: [(&str, &str); 24] = [
+    ("AUD", "A$"),
+    ("BRL", "R$"),
+    ("CAD", "CA$"),
+    ("CNY", "CN¥"),
+    ("EUR", "€"),
+    ("GBP", "£"),
+    ("HKD", "HK$"),
+    ("ILS", "₪"),
+    ("INR", "₹"),
+    ("JPY", "¥"),
+    ("KRW", "₩"),
+    ("MXN", "MX$"),
+    ("NGN", "₦"),
+    ("NZD", "NZ$"),
+    ("PHP", "₱"),
+    ("TRY", "₺"),
+    ("TWD", "NT$"),
+    ("UAH", "₴"),
+    ("USD", "$"),
+    ("VND", "₫"),
+    ("XAF", "FCFA"),
+    ("XCD", "EC$"),
+    ("XOF", "F\u{202f}CFA"),
+    ("XPF", "CFPF"),
+];
@@ -0,0 +1,679 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+//! Indexes over the currency table, built at compile time so lookups by
+//! code, name, alias and country don't scan every currency.
+//!
+//! Each index stores positions in `codes::ALL`, with `NONE` for an empty
+//! slot. Codes made of uppercase letters or digits are read as numbers in
+//! base 26 or 10 and index a table directly, which hashes them perfectly.
+//! Names, folded names and folded aliases are hashed perfectly too, into
+//! tables where a seed chosen for each bucket of keys gives every key its
+//! own slot. Countries, which may be subdivision codes like `SH-AC`, are
+//! kept sorted for binary search.
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use aliases::ALIASES;
+use codes::ALL;
+#[cfg(feature = "alloc")]
+use normalize::{MAX_FOLDED, fold_latin};
+use CurrencyCode;
+
+pub(crate) const NONE: u8 = u8::MAX;
+const CURRENCIES: usize = ALL.len();
+const BUCKETS: usize = 64;
+const COUNTRY_ENTRIES: usize = country_entries();
+#[cfg(feature = "alloc")]
+const ALIAS_ENTRIES: usize = alias_entries();
+
+static ALPHA3: [u8; 26 * 26 * 26] = alpha3_table();
+static NUM: [u8; 1000] = num_table();
+static NAME: PerfectHash = PerfectHash::new(&name_hashes());
+#[cfg(feature = "alloc")]
+static FOLDED_NAME: PerfectHash = PerfectHash::new(&folded_name_hashes());
+#[cfg(feature = "alloc")]
+static ALIAS: [(&str, u8); ALIAS_ENTRIES] = alias_table();
+#[cfg(feature = "alloc")]
+static ALIAS_HASH: PerfectHash = PerfectHash::new(&alias_hashes());
+static COUNTRY: [(&str, u8); COUNTRY_ENTRIES] = country_table();
+
+/// A table giving each of up to 255 keys its own one of 256 slots.
+///
+/// A key is looked up by its hash, which picks a bucket, and then by mixing
+/// the hash with the seed of that bucket, which picks the slot. Seeds are
+/// chosen when the table is built, so a lookup never probes; the slot holds
+/// the only key that can match, which the caller still compares.
+struct PerfectHash {
+    seeds: [u16; BUCKETS],
+    slots: [u8; 256],
+}
+
+impl PerfectHash {
+    /// Builds the table over keys with the given hashes, failing compilation
+    /// if two keys hash alike and so can't be told apart.
+    const fn new(hashes: &[u32]) -> PerfectHash {
+        assert!(hashes.len() < NONE as usize, "too many keys for a perfect hash");
+
+        let mut table = PerfectHash { seeds: [0; BUCKETS], slots: [NONE; 256] };
+        let mut sizes = [0; BUCKETS];
+        let mut i = 0;
+
+        while i < hashes.len() {
+            sizes[hashes[i] as usize % BUCKETS] += 1;
+            i += 1;
+        }
+
+        // Place the largest buckets first, while most slots are free.
+        loop {
+            let mut bucket = 0;
+            let mut b = 1;
+
+            while b < BUCKETS {
+                if sizes[b] > sizes[bucket] {
+                    bucket = b;
+                }
+
+                b += 1;
+            }
+
+            if sizes[bucket] == 0 {
+                return table;
+            }
+
+            let mut keys = [0; 8];
+            let mut len = 0;
+            let mut i = 0;
+
+            while i < hashes.len() {
+                if hashes[i] as usize % BUCKETS == bucket {
+                    assert!(len < keys.len(), "too many keys in a bucket");
+                    keys[len] = i;
+                    len += 1;
+                }
+
+                i += 1;
+            }
+
+            let mut seed = 1;
+
+            while !table.fits(hashes, &keys, len, seed) {
+                assert!(seed < u16::MAX, "keys hash alike");
+                seed += 1;
+            }
+
+            let mut k = 0;
+
+            while k < len {
+                table.slots[slot(hashes[keys[k]], seed)] = keys[k] as u8;
+                k += 1;
+            }
+
+            table.seeds[bucket] = seed;
+            sizes[bucket] = 0;
+        }
+    }
+
+    /// Returns whether a seed places the first `len` of the given keys in
+    /// free and distinct slots.
+    const fn fits(&self, hashes: &[u32], keys: &[usize; 8], len: usize, seed: u16) -> bool {
+        let mut k = 0;
+
+        while k < len {
+            let target = slot(hashes[keys[k]], seed);
+
+            if self.slots[target] != NONE {
+                return false;
+            }
+
+            let mut other = 0;
+
+            while other < k {
+                if target == slot(hashes[keys[other]], seed) {
+                    return false;
+                }
+
+                other += 1;
+            }
+
+            k += 1;
+        }
+
+        true
+    }
+
+    /// Returns the only key that can have the given hash, as an index into
+    /// the hashes the table was built from, or `NONE`.
+    const fn get(&self, hash: u32) -> u8 {
+        self.slots[slot(hash, self.seeds[hash as usize % BUCKETS])]
+    }
+}
+
+/// Returns the currency with the given Alpha3 code.
+pub(crate) const fn alpha3(alpha3: &str) -> Option<&'static CurrencyCode> {
+    match key(alpha3.as_bytes(), 3, b'A', 26) {
+        Some(key) => get(ALPHA3[key]),
+        None => None,
+    }
+}
+
+/// Reads an Alpha3 code as its key in the Alpha3 index, without branching
+/// on its bytes so that keys can be computed for many codes at once.
+///
+/// Codes that aren't three uppercase letters read the key of `AAA`, which is
+/// never assigned.
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn alpha3_key(code: [u8; 3]) -> u16 {
+    let letters = [code[0].wrapping_sub(b'A'), code[1].wrapping_sub(b'A'), code[2].wrapping_sub(b'A')];
+    let valid = (letters[0] < 26) & (letters[1] < 26) & (letters[2] < 26);
+    let key = (letters[0] as u16).wrapping_mul(26).wrapping_add(letters[1] as u16).wrapping_mul(26).wrapping_add(letters[2] as u16);
+
+    key * valid as u16
+}
+
+/// Returns the position in `ALL` of the currency with the given key in the
+/// Alpha3 index, or `NONE`.
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn alpha3_position(key: u16) -> u8 {
+    ALPHA3[key as usize]
+}
+
+/// Returns the currency with the given 3-digit numeric code.
+pub(crate) const fn num(num: &str) -> Option<&'static CurrencyCode> {
+    match key(num.as_bytes(), 3, b'0', 10) {
+        Some(key) => get(NUM[key]),
+        None => None,
+    }
+}
+
+/// Returns the currency with the given numeric code as a number.
+pub(crate) const fn num_value(num: u16) -> Option<&'static CurrencyCode> {
+    if num < 1000 {
+        get(NUM[num as usize])
+    } else {
+        None
+    }
+}
+
+/// Returns the currency with the given name.
+pub(crate) fn name(name: &str) -> Option<&'static CurrencyCode> {
+    get(NAME.get(hash(name.as_bytes()))).filter(|currency| currency.name == name)
+}
+
+/// Returns the currency whose name folds to the given folded name.
+#[cfg(feature = "alloc")]
+pub(crate) fn name_folded(folded: &str) -> Option<&'static CurrencyCode> {
+    get(FOLDED_NAME.get(hash(folded.as_bytes()))).filter(|currency| folds_to(currency.name, folded))
+}
+
+/// Returns the currency with an alias that folds to the given folded name.
+#[cfg(feature = "alloc")]
+pub(crate) fn alias(folded: &str) -> Option<&'static CurrencyCode> {
+    match ALIAS.get(ALIAS_HASH.get(hash(folded.as_bytes())) as usize) {
+        Some(&(alias, index)) if folds_to(alias, folded) => get(index),
+        _ => None,
+    }
+}
+
+/// Returns the currencies used by the country with the given Alpha2 code, in
+/// table order.
+pub(crate) fn country<'a>(country: &'a str) -> impl Iterator<Item = &'static CurrencyCode> + 'a {
+    let start = COUNTRY.partition_point(|&(code, _)| code < country);
+
+    COUNTRY[start..]
+        .iter()
+        .take_while(move |&&(code, _)| code == country)
+        .map(|&(_, index)| &ALL[index as usize])
+}
+
+/// Returns the position in `ALL` of a currency taken from it.
+pub(crate) const fn position(currency: &CurrencyCode) -> usize {
+    match key(currency.num.as_bytes(), 3, b'0', 10) {
+        Some(key) => NUM[key] as usize,
+        None => 0,
+    }
+}
+
+/// Returns the position in `ALL` of the currency with the given numeric
+/// code, which must exist.
+pub(crate) const fn num_position(num: u16) -> u8 {
+    NUM[num as usize]
+}
+
+/// Returns the currency whose Alpha3 code is closest to a mistyped one,
+/// if any is close enough to suggest.
+///
+/// Codes score for each character they share in the same position, ignoring
+/// case, and need at least two. Earlier positions count for more, so "EUD"
+/// suggests EUR rather than AUD, and swapped neighbours count as a near
+/// match, so "GPB" suggests GBP.
+pub(crate) const fn nearest_alpha3(code: &[u8]) -> Option<&'static CurrencyCode> {
+    nearest(code, false)
+}
+
+/// Returns the currency whose numeric code is closest to a mistyped one,
+/// scored like `nearest_alpha3`, preferring the nearest number on a tie.
+pub(crate) const fn nearest_num(num: u16) -> Option<&'static CurrencyCode> {
+    if num >= 1000 {
+        return None;
+    }
+
+    let digits = [b'0' + (num / 100) as u8, b'0' + (num / 10 % 10) as u8, b'0' + (num % 10) as u8];
+
+    nearest(&digits, true)
+}
+
+const fn nearest(code: &[u8], numeric: bool) -> Option<&'static CurrencyCode> {
+    let mut best = None;
+    let mut best_weight = 0;
+    let mut best_distance = u32::MAX;
+    let mut i = 0;
+
+    while i < ALL.len() {
+        let candidate = if numeric { ALL[i].num.as_bytes() } else { ALL[i].alpha3.as_bytes() };
+        let (mut matches, mut weight) = (0, 0);
+        let mut j = 0;
+
+        while j < code.len() && j < candidate.len() {
+            if code[j].to_ascii_uppercase() == candidate[j] {
+                matches += 1;
+                weight += 1 << (candidate.len() - j);
+            }
+
+            j += 1;
+        }
+
+        if transposed(code, candidate) {
+            matches = code.len();
+            weight = 11;
+        }
+
+        // Numeric codes that score alike are told apart by how far they are.
+        let distance = if numeric { value(code).abs_diff(value(candidate)) } else { 0 };
+
+        if matches >= 2 && (weight > best_weight || weight == best_weight && distance < best_distance) {
+            best = Some(&ALL[i]);
+            best_weight = weight;
+            best_distance = distance;
+        }
+
+        i += 1;
+    }
+
+    best
+}
+
+/// Reads digits as a decimal number.
+const fn value(digits: &[u8]) -> u32 {
+    let mut value = 0;
+    let mut i = 0;
+
+    while i < digits.len() {
+        value = value * 10 + (digits[i] - b'0') as u32;
+        i += 1;
+    }
+
+    value
+}
+
+/// Returns whether `code` is `candidate` with two neighbours swapped.
+const fn transposed(code: &[u8], candidate: &[u8]) -> bool {
+    if code.len() != candidate.len() {
+        return false;
+    }
+
+    let mut swap = 0;
+
+    while swap + 1 < code.len() {
+        let mut equal = true;
+        let mut j = 0;
+
+        while j < code.len() {
+            let from = if j == swap { j + 1 } else if j == swap + 1 { j - 1 } else { j };
+
+            if code[from].to_ascii_uppercase() != candidate[j] {
+                equal = false;
+            }
+
+            j += 1;
+        }
+
+        if equal {
+            return true;
+        }
+
+        swap += 1;
+    }
+
+    false
+}
+
+const fn get(index: u8) -> Option<&'static CurrencyCode> {
+    if index == NONE {
+        None
+    } else {
+        Some(&ALL[index as usize])
+    }
+}
+
+/// Reads a code of `len` characters from `first` to `first + radix - 1` as a
+/// number in that radix.
+const fn key(bytes: &[u8], len: usize, first: u8, radix: usize) -> Option<usize> {
+    if bytes.len() != len {
+        return None;
+    }
+
+    let mut key = 0;
+    let mut i = 0;
+
+    while i < len {
+        if bytes[i] < first || bytes[i] - first >= radix as u8 {
+            return None;
+        }
+
+        key = key * radix + (bytes[i] - first) as usize;
+        i += 1;
+    }
+
+    Some(key)
+}
+
+/// Hashes bytes with FNV-1a.
+const fn hash(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u32).wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Mixes a hash with a seed into a slot of a `PerfectHash`, with the
+/// finalizer of MurmurHash3.
+const fn slot(hash: u32, seed: u16) -> usize {
+    let mut hash = hash ^ (seed as u32).wrapping_mul(0x9e37_79b9);
+
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^= hash >> 16;
+
+    hash as u8 as usize
+}
+
+/// Returns the folded form of a name or alias.
+#[cfg(feature = "alloc")]
+const fn folded(name: &str) -> ([u8; MAX_FOLDED], usize) {
+    match fold_latin(name) {
+        Some(folded) => folded,
+        None => panic!("names and aliases must fold to ASCII"),
+    }
+}
+
+/// Hashes the folded form of a name or alias.
+#[cfg(feature = "alloc")]
+const fn folded_hash(name: &str) -> u32 {
+    let (folded, len) = folded(name);
+
+    hash(folded.split_at(len).0)
+}
+
+/// Returns whether a folded alias folds like the name of a currency.
+#[cfg(feature = "alloc")]
+const fn folds_like_name(alias: &[u8]) -> bool {
+    match get(FOLDED_NAME.get(hash(alias))) {
+        Some(currency) => {
+            let (name, len) = folded(currency.name);
+
+            matches!(compare(name.split_at(len).0, alias), Ordering::Equal)
+        },
+        None => false,
+    }
+}
+
+/// Returns whether a folded alias reads as the Alpha3 code of a currency,
+/// ignoring case.
+#[cfg(feature = "alloc")]
+const fn reads_as_alpha3(alias: &[u8]) -> bool {
+    if alias.len() != 3 {
+        return false;
+    }
+
+    let code = [alias[0].to_ascii_uppercase(), alias[1].to_ascii_uppercase(), alias[2].to_ascii_uppercase()];
+
+    match key(&code, 3, b'A', 26) {
+        Some(key) => ALPHA3[key] != NONE,
+        None => false,
+    }
+}
+
+/// Returns whether a name or alias folds to the given folded name.
+#[cfg(feature = "alloc")]
+fn folds_to(name: &str, folded: &str) -> bool {
+    match fold_latin(name) {
+        Some((bytes, len)) => bytes[..len] == *folded.as_bytes(),
+        None => false,
+    }
+}
+
+const fn alpha3_table() -> [u8; 26 * 26 * 26] {
+    let mut table = [NONE; 26 * 26 * 26];
+    let mut i = 0;
+
+    while i < ALL.len() {
+        let key = match key(ALL[i].alpha3.as_bytes(), 3, b'A', 26) {
+            Some(key) => key,
+            None => panic!("Alpha3 codes must be three uppercase letters"),
+        };
+
+        assert!(table[key] == NONE, "duplicate Alpha3 code");
+        table[key] = i as u8;
+        i += 1;
+    }
+
+    assert!(table[0] == NONE, "AAA is the slot of invalid codes");
+
+    table
+}
+
+const fn num_table() -> [u8; 1000] {
+    let mut table = [NONE; 1000];
+    let mut i = 0;
+
+    while i < ALL.len() {
+        let key = match key(ALL[i].num.as_bytes(), 3, b'0', 10) {
+            Some(key) => key,
+            None => panic!("numeric codes must be three digits"),
+        };
+
+        assert!(table[key] == NONE, "duplicate numeric code");
+        table[key] = i as u8;
+        i += 1;
+    }
+
+    table
+}
+
+/// Hashes the name of every currency, in table order.
+const fn name_hashes() -> [u32; CURRENCIES] {
+    let mut hashes = [0; CURRENCIES];
+    let mut i = 0;
+
+    while i < CURRENCIES {
+        hashes[i] = hash(ALL[i].name.as_bytes());
+        i += 1;
+    }
+
+    hashes
+}
+
+/// Hashes the folded name of every currency, in table order.
+#[cfg(feature = "alloc")]
+const fn folded_name_hashes() -> [u32; CURRENCIES] {
+    let mut hashes = [0; CURRENCIES];
+    let mut i = 0;
+
+    while i < CURRENCIES {
+        hashes[i] = folded_hash(ALL[i].name);
+        i += 1;
+    }
+
+    hashes
+}
+
+#[cfg(feature = "alloc")]
+const fn alias_entries() -> usize {
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < ALIASES.len() {
+        count += ALIASES[i].1.len();
+        i += 1;
+    }
+
+    count
+}
+
+/// Lists every alias with the position of its currency, in the order of the
+/// alias table.
+#[cfg(feature = "alloc")]
+const fn alias_table() -> [(&'static str, u8); ALIAS_ENTRIES] {
+    let mut table = [("", NONE); ALIAS_ENTRIES];
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < ALIASES.len() {
+        let (code, names) = ALIASES[i];
+        let index = match key(code.as_bytes(), 3, b'A', 26) {
+            Some(key) => ALPHA3[key],
+            None => NONE,
+        };
+        let mut j = 0;
+
+        assert!(index != NONE, "aliases must be of a listed currency");
+
+        while j < names.len() {
+            table[len] = (names[j], index);
+            len += 1;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    table
+}
+
+/// Hashes the folded form of every alias, failing compilation if an alias
+/// folds like a name or reads as an Alpha3 code, which `lookup` would find
+/// as two forms.
+#[cfg(feature = "alloc")]
+const fn alias_hashes() -> [u32; ALIAS_ENTRIES] {
+    let mut hashes = [0; ALIAS_ENTRIES];
+    let mut i = 0;
+
+    while i < ALIAS_ENTRIES {
+        let (folded, len) = folded(ALIAS[i].0);
+        let alias = folded.split_at(len).0;
+
+        assert!(!folds_like_name(alias), "aliases must not fold like a currency name");
+        assert!(!reads_as_alpha3(alias), "aliases must not read as an Alpha3 code");
+        hashes[i] = hash(alias);
+        i += 1;
+    }
+
+    hashes
+}
+
+const fn country_entries() -> usize {
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < ALL.len() {
+        count += ALL[i].countries.len();
+        i += 1;
+    }
+
+    count
+}
+
+pub(crate) const fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return if a[i] < b[i] { Ordering::Less } else { Ordering::Greater };
+        }
+
+        i += 1;
+    }
+
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Lists every (country, currency) pair, sorted by country and then by
+/// position in the table.
+const fn country_table() -> [(&'static str, u8); COUNTRY_ENTRIES] {
+    let mut table = [("", NONE); COUNTRY_ENTRIES];
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < ALL.len() {
+        let mut j = 0;
+
+        while j < ALL[i].countries.len() {
+            let country = ALL[i].countries[j];
+            let mut slot = len;
+
+            // Insertion sort, as currencies are visited in table order.
+            while slot > 0 && matches!(compare(table[slot - 1].0.as_bytes(), country.as_bytes()), Ordering::Greater) {
+                table[slot] = table[slot - 1];
+                slot -= 1;
+            }
+
+            table[slot] = (country, i as u8);
+            len += 1;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    table
+}
@@ -24,9 +24,84 @@
 //
 // Originally by zeyla on GitHub.
 
+#![no_std]
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "ecb")]
+pub mod ecb;
+
+mod aliases;
+#[cfg(feature = "alloc")]
+mod balances;
+#[cfg(feature = "alloc")]
+mod bulk;
 mod codes;
+mod collections;
+mod compact;
+mod countries;
+mod currency;
+mod exchange;
+#[cfg(feature = "alloc")]
+mod format;
+mod index;
+#[cfg(feature = "alloc")]
+mod locales;
+#[cfg(feature = "alloc")]
+mod lookup;
+#[doc(hidden)]
+pub mod macros;
+mod money;
+#[cfg(feature = "alloc")]
+mod normalize;
+#[cfg(feature = "alloc")]
+mod parse;
+mod query;
+#[cfg(feature = "alloc")]
+mod scan;
+#[cfg(feature = "alloc")]
+mod search;
+#[cfg(feature = "alloc")]
+mod store;
+mod withdrawn;
+#[cfg(feature = "alloc")]
+mod words;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub use aliases::aliases;
+#[cfg(feature = "alloc")]
+pub use balances::{Balances, BalancesIter};
+#[cfg(feature = "alloc")]
+pub use bulk::{RowError, Validation, parse_many, validate_bytes};
 pub use codes::all;
+pub use collections::{CurrencyMap, CurrencyMapIter, CurrencySet, CurrencySetIter};
+pub use compact::Currency;
+pub use countries::Region;
+pub use currency::ParseCurrencyError;
+pub use exchange::{ExchangeRate, ParseRateError, Rate, convert};
+#[cfg(feature = "alloc")]
+pub use format::{CurrencyDisplay, Locale, MoneyFormatter, Notation, NumberingSystem, PluralRule};
+#[cfg(feature = "alloc")]
+pub use lookup::{LookupError, Match, MatchKind, lookup};
+pub use money::{Money, RoundingMode};
+#[cfg(feature = "alloc")]
+pub use parse::{ParseMoneyError, parse_money};
+pub use query::{Kind, Query, QueryIter, SortBy, Status};
+#[cfg(feature = "alloc")]
+pub use scan::{Mention, scan};
+#[cfg(feature = "alloc")]
+pub use search::{SearchResult, search};
+#[cfg(feature = "alloc")]
+pub use store::{Date, ParseDateError, RateStore};
+pub use withdrawn::withdrawn;
+#[cfg(feature = "alloc")]
+pub use words::{Language, amount_in_words};
 
 /// Data for each Currency Code defined by ISO 4217.
 #[derive(Clone, Debug)]
@@ -44,26 +119,135 @@ pub struct CurrencyCode {
 }
 
 /// Returns the CurrencyCode with the given Alpha3 code, if one exists.
-pub fn alpha3(alpha3: &str) -> Option<&'static CurrencyCode> {
-    all().iter().find(|c| c.alpha3 == alpha3)
+pub const fn alpha3(alpha3: &str) -> Option<&'static CurrencyCode> {
+    index::alpha3(alpha3)
+}
+
+/// Returns the CurrencyCode with the given Alpha3 code, panicking if none
+/// exists.
+///
+/// As a `const fn`, this can define constants and statics, in which case an
+/// unknown code fails compilation:
+///
+/// ```rust
+/// use iso4217::CurrencyCode;
+///
+/// const BASE: &CurrencyCode = iso4217::alpha3_const("EUR");
+///
+/// assert_eq!(BASE.num, "978");
+/// ```
+///
+/// ```rust,compile_fail
+/// use iso4217::CurrencyCode;
+///
+/// const BASE: &CurrencyCode = iso4217::alpha3_const("EUD");
+/// ```
+pub const fn alpha3_const(alpha3: &str) -> &'static CurrencyCode {
+    match index::alpha3(alpha3) {
+        Some(currency) => currency,
+        None => panic!("unknown Alpha3 code"),
+    }
+}
+
+/// Returns the CurrencyCode with the given Alpha3 code, ignoring case and
+/// surrounding whitespace, if one exists.
+#[cfg(feature = "alloc")]
+pub fn alpha3_normalized(alpha3: &str) -> Option<&'static CurrencyCode> {
+    index::alpha3(&normalize::fold(alpha3).to_ascii_uppercase())
 }
 
 /// Returns a vector of all CurrencyCodes that use a given Alpha2 code.
+#[cfg(feature = "alloc")]
 pub fn country(country: &str) -> Vec<&'static CurrencyCode> {
-    all().iter().filter(|c| c.countries.contains(&country)).collect()
+    country_iter(country).collect()
+}
+
+/// Returns an iterator over all CurrencyCodes that use a given Alpha2 code,
+/// without allocating.
+pub fn country_iter<'a>(country: &'a str) -> impl Iterator<Item = &'static CurrencyCode> + 'a {
+    index::country(country)
 }
 
 /// Returns a vector of all CurrencyCodes with the specified decimal place.
+#[cfg(feature = "alloc")]
 pub fn exp(exp: i8) -> Vec<&'static CurrencyCode> {
-    all().iter().filter(|c| c.exp == exp).collect()
+    exp_iter(exp).collect()
+}
+
+/// Returns an iterator over all CurrencyCodes with the specified decimal
+/// place, without allocating.
+pub fn exp_iter(exp: i8) -> impl Iterator<Item = &'static CurrencyCode> {
+    all().iter().filter(move |c| c.exp == exp)
 }
 
 /// Returns the CurrencyCode with the given name, if one exists.
 pub fn name(name: &str) -> Option<&'static CurrencyCode> {
-    all().iter().find(|c| c.name == name)
+    index::name(name)
+}
+
+/// Returns the CurrencyCode with the given name, ignoring case, diacritics
+/// and extra whitespace, if one exists.
+///
+/// "us dollar" does not match, but "united states  DOLLAR" and "Polish
+/// zloty" do.
+#[cfg(feature = "alloc")]
+pub fn name_normalized(name: &str) -> Option<&'static CurrencyCode> {
+    index::name_folded(&normalize::fold(name))
+}
+
+/// Returns the CurrencyCode with the given name or one of its aliases,
+/// ignoring case, diacritics and extra whitespace, if one exists.
+///
+/// Unlike `name_normalized`, this also resolves names found in third-party
+/// feeds, such as "US Dollar", "Sterling" or "Renminbi". See `aliases` for
+/// the names known for each currency.
+#[cfg(feature = "alloc")]
+pub fn alias(name: &str) -> Option<&'static CurrencyCode> {
+    let name = normalize::fold(name);
+
+    index::name_folded(&name).or_else(|| index::alias(&name))
 }
 
 /// Returns the CurrencyCode with the given numerical code, if one exists.
-pub fn num(num: &str) -> Option<&'static CurrencyCode> {
-    all().iter().find(|c| c.num == num)
+pub const fn num(num: &str) -> Option<&'static CurrencyCode> {
+    index::num(num)
+}
+
+/// Returns the CurrencyCode with the given numerical code, panicking if none
+/// exists.
+///
+/// As a `const fn`, this can define constants and statics, in which case an
+/// unknown code fails compilation:
+///
+/// ```rust
+/// use iso4217::CurrencyCode;
+///
+/// const BASE: &CurrencyCode = iso4217::num_const(978);
+///
+/// assert_eq!(BASE.alpha3, "EUR");
+/// ```
+///
+/// ```rust,compile_fail
+/// use iso4217::CurrencyCode;
+///
+/// const BASE: &CurrencyCode = iso4217::num_const(979);
+/// ```
+pub const fn num_const(num: u16) -> &'static CurrencyCode {
+    match index::num_value(num) {
+        Some(currency) => currency,
+        None => panic!("unknown numeric code"),
+    }
+}
+
+/// Returns the CurrencyCode with the given numerical code, ignoring
+/// surrounding whitespace and missing leading zeros, if one exists.
+#[cfg(feature = "alloc")]
+pub fn num_normalized(num: &str) -> Option<&'static CurrencyCode> {
+    let num = normalize::fold(num);
+
+    if num.is_empty() || num.len() > 3 || !num.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    index::num(&format!("{:0>3}", num))
 }
\ No newline at end of file
@@ -0,0 +1,574 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use {Locale, NumberingSystem, PluralRule};
+
+const LOCALES: [Locale; 30] = [
+    // Sorted by tag.
+    Locale {
+        tag: "ar-EG",
+        decimal: "\u{66b}",
+        group: "\u{66c}",
+        minus: "\u{61c}-",
+        numbering: NumberingSystem::Arab,
+        min_grouping: 1,
+        pattern: "\u{200f}#,##0.00\u{a0}¤",
+        accounting_pattern: "\u{200f}#,##0.00\u{a0}¤",
+        compact_short: &[],
+        compact_long: &[],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("EGP", "ج.م.\u{200f}")],
+    },
+    Locale {
+        tag: "bn",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Beng,
+        min_grouping: 1,
+        pattern: "#,##,##0.00¤",
+        accounting_pattern: "#,##,##0.00¤;(#,##,##0.00¤)",
+        compact_short: &[],
+        compact_long: &[],
+        plural: PluralRule::UpToOne,
+        symbols: &[("BDT", "৳")],
+    },
+    Locale {
+        tag: "de",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤",
+        compact_short: &[(6, "0\u{a0}Mio.\u{a0}¤"), (9, "0\u{a0}Mrd.\u{a0}¤"), (12, "0\u{a0}Bio.\u{a0}¤")],
+        compact_long: &[
+            (3, "0 Tausend", "0 Tausend"),
+            (6, "0 Million", "0 Millionen"),
+            (9, "0 Milliarde", "0 Milliarden"),
+            (12, "0 Billion", "0 Billionen"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "de-AT",
+        decimal: ",",
+        group: "\u{a0}",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤\u{a0}#,##0.00",
+        accounting_pattern: "¤\u{a0}#,##0.00",
+        compact_short: &[(6, "0\u{a0}Mio.\u{a0}¤"), (9, "0\u{a0}Mrd.\u{a0}¤"), (12, "0\u{a0}Bio.\u{a0}¤")],
+        compact_long: &[
+            (3, "0 Tausend", "0 Tausend"),
+            (6, "0 Million", "0 Millionen"),
+            (9, "0 Milliarde", "0 Milliarden"),
+            (12, "0 Billion", "0 Billionen"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "de-CH",
+        decimal: ".",
+        group: "’",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤\u{a0}#,##0.00;¤-#,##0.00",
+        accounting_pattern: "¤\u{a0}#,##0.00;¤-#,##0.00",
+        compact_short: &[(6, "0\u{a0}Mio.\u{a0}¤"), (9, "0\u{a0}Mrd.\u{a0}¤"), (12, "0\u{a0}Bio.\u{a0}¤")],
+        compact_long: &[
+            (3, "0 Tausend", "0 Tausend"),
+            (6, "0 Million", "0 Millionen"),
+            (9, "0 Milliarde", "0 Milliarden"),
+            (12, "0 Billion", "0 Billionen"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "de-DE",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤",
+        compact_short: &[(6, "0\u{a0}Mio.\u{a0}¤"), (9, "0\u{a0}Mrd.\u{a0}¤"), (12, "0\u{a0}Bio.\u{a0}¤")],
+        compact_long: &[
+            (3, "0 Tausend", "0 Tausend"),
+            (6, "0 Million", "0 Millionen"),
+            (9, "0 Milliarde", "0 Milliarden"),
+            (12, "0 Billion", "0 Billionen"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "en",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(3, "¤0K"), (6, "¤0M"), (9, "¤0B"), (12, "¤0T")],
+        compact_long: &[
+            (3, "0 thousand", "0 thousand"),
+            (6, "0 million", "0 million"),
+            (9, "0 billion", "0 billion"),
+            (12, "0 trillion", "0 trillion"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "en-AU",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(3, "¤0K"), (6, "¤0M"), (9, "¤0B"), (12, "¤0T")],
+        compact_long: &[
+            (3, "0 thousand", "0 thousand"),
+            (6, "0 million", "0 million"),
+            (9, "0 billion", "0 billion"),
+            (12, "0 trillion", "0 trillion"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("AUD", "$"), ("USD", "USD")],
+    },
+    Locale {
+        tag: "en-CA",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(3, "¤0K"), (6, "¤0M"), (9, "¤0B"), (12, "¤0T")],
+        compact_long: &[
+            (3, "0 thousand", "0 thousand"),
+            (6, "0 million", "0 million"),
+            (9, "0 billion", "0 billion"),
+            (12, "0 trillion", "0 trillion"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("CAD", "$"), ("USD", "US$")],
+    },
+    Locale {
+        tag: "en-GB",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(3, "¤0K"), (6, "¤0M"), (9, "¤0B"), (12, "¤0T")],
+        compact_long: &[
+            (3, "0 thousand", "0 thousand"),
+            (6, "0 million", "0 million"),
+            (9, "0 billion", "0 billion"),
+            (12, "0 trillion", "0 trillion"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("USD", "US$")],
+    },
+    Locale {
+        tag: "en-IE",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(3, "¤0K"), (6, "¤0M"), (9, "¤0B"), (12, "¤0T")],
+        compact_long: &[
+            (3, "0 thousand", "0 thousand"),
+            (6, "0 million", "0 million"),
+            (9, "0 billion", "0 billion"),
+            (12, "0 trillion", "0 trillion"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("USD", "US$")],
+    },
+    Locale {
+        tag: "en-IN",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(3, "¤0K"), (5, "¤0L"), (7, "¤0Cr")],
+        compact_long: &[
+            (3, "0 thousand", "0 thousand"),
+            (5, "0 lakh", "0 lakh"),
+            (7, "0 crore", "0 crore"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "en-US",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(3, "¤0K"), (6, "¤0M"), (9, "¤0B"), (12, "¤0T")],
+        compact_long: &[
+            (3, "0 thousand", "0 thousand"),
+            (6, "0 million", "0 million"),
+            (9, "0 billion", "0 billion"),
+            (12, "0 trillion", "0 trillion"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "es",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 2,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤",
+        compact_short: &[(3, "0\u{a0}mil\u{a0}¤"), (6, "0\u{a0}M\u{a0}¤"), (12, "0\u{a0}B\u{a0}¤")],
+        compact_long: &[
+            (3, "0 mil", "0 mil"),
+            (6, "0 millón", "0 millones"),
+            (12, "0 billón", "0 billones"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("USD", "US$")],
+    },
+    Locale {
+        tag: "es-ES",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 2,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤",
+        compact_short: &[(3, "0\u{a0}mil\u{a0}¤"), (6, "0\u{a0}M\u{a0}¤"), (12, "0\u{a0}B\u{a0}¤")],
+        compact_long: &[
+            (3, "0 mil", "0 mil"),
+            (6, "0 millón", "0 millones"),
+            (12, "0 billón", "0 billones"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("USD", "US$")],
+    },
+    Locale {
+        tag: "es-MX",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00",
+        compact_short: &[(3, "¤0\u{a0}k"), (6, "¤0\u{a0}M"), (12, "¤0\u{a0}B")],
+        compact_long: &[
+            (3, "0 mil", "0 mil"),
+            (6, "0 millón", "0 millones"),
+            (12, "0 billón", "0 billones"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("MXN", "$"), ("USD", "USD")],
+    },
+    Locale {
+        tag: "fa",
+        decimal: "\u{66b}",
+        group: "\u{66c}",
+        minus: "\u{200e}\u{2212}",
+        numbering: NumberingSystem::ArabExt,
+        min_grouping: 1,
+        pattern: "\u{200e}¤#,##0.00",
+        accounting_pattern: "\u{200e}¤#,##0.00;\u{200e}(¤#,##0.00)",
+        compact_short: &[],
+        compact_long: &[],
+        plural: PluralRule::UpToOne,
+        symbols: &[("IRR", "ریال")],
+    },
+    Locale {
+        tag: "fr",
+        decimal: ",",
+        group: "\u{202f}",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤;(#,##0.00\u{a0}¤)",
+        compact_short: &[(3, "0\u{a0}k\u{a0}¤"), (6, "0\u{a0}M\u{a0}¤"), (9, "0\u{a0}Md\u{a0}¤"), (12, "0\u{a0}Bn\u{a0}¤")],
+        compact_long: &[
+            (3, "0 mille", "0 mille"),
+            (6, "0 million", "0 millions"),
+            (9, "0 milliard", "0 milliards"),
+            (12, "0 billion", "0 billions"),
+        ],
+        plural: PluralRule::BelowTwo,
+        symbols: &[("AUD", "$AU"), ("CAD", "$CA"), ("GBP", "£GB"), ("JPY", "JPY"), ("USD", "$US")],
+    },
+    Locale {
+        tag: "fr-FR",
+        decimal: ",",
+        group: "\u{202f}",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤;(#,##0.00\u{a0}¤)",
+        compact_short: &[(3, "0\u{a0}k\u{a0}¤"), (6, "0\u{a0}M\u{a0}¤"), (9, "0\u{a0}Md\u{a0}¤"), (12, "0\u{a0}Bn\u{a0}¤")],
+        compact_long: &[
+            (3, "0 mille", "0 mille"),
+            (6, "0 million", "0 millions"),
+            (9, "0 milliard", "0 milliards"),
+            (12, "0 billion", "0 billions"),
+        ],
+        plural: PluralRule::BelowTwo,
+        symbols: &[("AUD", "$AU"), ("CAD", "$CA"), ("GBP", "£GB"), ("JPY", "JPY"), ("USD", "$US")],
+    },
+    Locale {
+        tag: "hi",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##,##0.00",
+        accounting_pattern: "¤#,##,##0.00",
+        compact_short: &[(3, "¤0 हज़ार"), (5, "¤0 लाख"), (7, "¤0 क॰")],
+        compact_long: &[
+            (3, "0 हज़ार", "0 हज़ार"),
+            (5, "0 लाख", "0 लाख"),
+            (7, "0 करोड़", "0 करोड़"),
+        ],
+        plural: PluralRule::UpToOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "it",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤",
+        compact_short: &[(6, "0\u{a0}Mio\u{a0}¤"), (9, "0\u{a0}Mrd\u{a0}¤"), (12, "0\u{a0}Bln\u{a0}¤")],
+        compact_long: &[
+            (3, "0 mille", "0 mila"),
+            (6, "0 milione", "0 milioni"),
+            (9, "0 miliardo", "0 miliardi"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("USD", "USD")],
+    },
+    Locale {
+        tag: "ja",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(4, "¤0万"), (8, "¤0億"), (12, "¤0兆")],
+        compact_long: &[
+            (4, "0万", "0万"),
+            (8, "0億", "0億"),
+            (12, "0兆", "0兆"),
+        ],
+        plural: PluralRule::Other,
+        symbols: &[("CNY", "元"), ("JPY", "￥")],
+    },
+    Locale {
+        tag: "mr",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Deva,
+        min_grouping: 1,
+        pattern: "¤#,##,##0.00",
+        accounting_pattern: "¤#,##,##0.00",
+        compact_short: &[],
+        compact_long: &[],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[],
+    },
+    Locale {
+        tag: "nl",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤\u{a0}#,##0.00;¤\u{a0}-#,##0.00",
+        accounting_pattern: "¤\u{a0}#,##0.00;(¤\u{a0}#,##0.00)",
+        compact_short: &[(3, "¤\u{a0}0K"), (6, "¤\u{a0}0\u{a0}mln."), (9, "¤\u{a0}0\u{a0}mld."), (12, "¤\u{a0}0\u{a0}bln.")],
+        compact_long: &[
+            (3, "0 duizend", "0 duizend"),
+            (6, "0 miljoen", "0 miljoen"),
+            (9, "0 miljard", "0 miljard"),
+            (12, "0 biljoen", "0 biljoen"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("USD", "US$")],
+    },
+    Locale {
+        tag: "pl",
+        decimal: ",",
+        group: "\u{a0}",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 2,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤;(#,##0.00\u{a0}¤)",
+        compact_short: &[(3, "0\u{a0}tys.\u{a0}¤"), (6, "0\u{a0}mln\u{a0}¤"), (9, "0\u{a0}mld\u{a0}¤"), (12, "0\u{a0}bln\u{a0}¤")],
+        compact_long: &[],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("PLN", "zł"), ("USD", "USD")],
+    },
+    Locale {
+        tag: "pt",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤\u{a0}#,##0.00",
+        accounting_pattern: "¤\u{a0}#,##0.00",
+        compact_short: &[(3, "¤\u{a0}0\u{a0}mil"), (6, "¤\u{a0}0\u{a0}mi"), (9, "¤\u{a0}0\u{a0}bi"), (12, "¤\u{a0}0\u{a0}tri")],
+        compact_long: &[
+            (3, "0 mil", "0 mil"),
+            (6, "0 milhão", "0 milhões"),
+            (9, "0 bilhão", "0 bilhões"),
+            (12, "0 trilhão", "0 trilhões"),
+        ],
+        plural: PluralRule::BelowTwo,
+        symbols: &[("USD", "US$")],
+    },
+    Locale {
+        tag: "pt-BR",
+        decimal: ",",
+        group: ".",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤\u{a0}#,##0.00",
+        accounting_pattern: "¤\u{a0}#,##0.00",
+        compact_short: &[(3, "¤\u{a0}0\u{a0}mil"), (6, "¤\u{a0}0\u{a0}mi"), (9, "¤\u{a0}0\u{a0}bi"), (12, "¤\u{a0}0\u{a0}tri")],
+        compact_long: &[
+            (3, "0 mil", "0 mil"),
+            (6, "0 milhão", "0 milhões"),
+            (9, "0 bilhão", "0 bilhões"),
+            (12, "0 trilhão", "0 trilhões"),
+        ],
+        plural: PluralRule::BelowTwo,
+        symbols: &[("USD", "US$")],
+    },
+    Locale {
+        tag: "sv",
+        decimal: ",",
+        group: "\u{a0}",
+        minus: "\u{2212}",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "#,##0.00\u{a0}¤",
+        accounting_pattern: "#,##0.00\u{a0}¤",
+        compact_short: &[(3, "0\u{a0}tn\u{a0}¤"), (6, "0\u{a0}mn\u{a0}¤"), (9, "0\u{a0}md\u{a0}¤"), (12, "0\u{a0}bn\u{a0}¤")],
+        compact_long: &[
+            (3, "0 tusen", "0 tusen"),
+            (6, "0 miljon", "0 miljoner"),
+            (9, "0 miljard", "0 miljarder"),
+            (12, "0 biljon", "0 biljoner"),
+        ],
+        plural: PluralRule::ExactlyOne,
+        symbols: &[("SEK", "kr"), ("USD", "US$")],
+    },
+    Locale {
+        tag: "zh",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(4, "¤0万"), (8, "¤0亿"), (12, "¤0万亿")],
+        compact_long: &[
+            (4, "0万", "0万"),
+            (8, "0亿", "0亿"),
+            (12, "0万亿", "0万亿"),
+        ],
+        plural: PluralRule::Other,
+        symbols: &[("CNY", "¥"), ("JPY", "JP¥"), ("USD", "US$")],
+    },
+    Locale {
+        tag: "zh-TW",
+        decimal: ".",
+        group: ",",
+        minus: "-",
+        numbering: NumberingSystem::Latn,
+        min_grouping: 1,
+        pattern: "¤#,##0.00",
+        accounting_pattern: "¤#,##0.00;(¤#,##0.00)",
+        compact_short: &[(4, "¤0萬"), (8, "¤0億"), (12, "¤0兆")],
+        compact_long: &[
+            (4, "0萬", "0萬"),
+            (8, "0億", "0億"),
+            (12, "0兆", "0兆"),
+        ],
+        plural: PluralRule::Other,
+        symbols: &[("TWD", "$"), ("USD", "US$")],
+    },
+];
+
+/// Returns all locales with formatting data.
+// Data is taken from the Unicode Common Locale Data Repository (CLDR):
+// https://cldr.unicode.org/
+pub fn all() -> &'static [Locale] {
+    &LOCALES
+}
@@ -0,0 +1,123 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+use normalize::fold;
+use {CurrencyCode, alpha3_normalized, index, num_normalized};
+
+/// The form of identifier matched by [`lookup`](fn.lookup.html).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchKind {
+    /// The 3-digit numeric code, as in `840`
+    Numeric,
+    /// The Alpha3 code, as in `usd`
+    Alpha3,
+    /// The name, as in `United States dollar`
+    Name,
+    /// An alias of the name, as in `US dollar`
+    Alias,
+}
+
+/// A currency found by [`lookup`](fn.lookup.html), with the form of
+/// identifier it was found by.
+#[derive(Clone, Debug)]
+pub struct Match {
+    /// The currency found
+    pub currency: &'static CurrencyCode,
+    /// The form of identifier that matched
+    pub kind: MatchKind,
+}
+
+/// An error returned when [`lookup`](fn.lookup.html) can't resolve an
+/// identifier to a single currency.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LookupError {
+    /// The identifier is empty or only whitespace.
+    Empty,
+    /// The identifier is not a known code, name or alias.
+    NotFound(String),
+    /// The identifier matches different currencies as different forms; their
+    /// Alpha3 codes are given.
+    Conflict(Vec<&'static str>),
+}
+
+impl Display for LookupError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            LookupError::Empty => f.write_str("empty currency identifier"),
+            LookupError::NotFound(ref text) => write!(f, "unknown currency {:?}", text),
+            LookupError::Conflict(ref codes) => {
+                write!(f, "conflicting currency identifier, could be any of {}", codes.join(", "))
+            },
+        }
+    }
+}
+
+impl Error for LookupError {}
+
+/// Returns the currency identified by a numeric code, Alpha3 code, name or
+/// alias, detecting which form the input takes.
+///
+/// Case, diacritics, surrounding whitespace and missing leading zeros of
+/// numeric codes are ignored, so `"840"`, `"usd"`, `"US dollar"` and
+/// `"United States Dollar"` all return USD, each with a different
+/// [`MatchKind`](enum.MatchKind.html). An input matching different
+/// currencies as different forms is reported as a conflict rather than
+/// resolved by preference.
+pub fn lookup(input: &str) -> Result<Match, LookupError> {
+    let folded = fold(input);
+
+    if folded.is_empty() {
+        return Err(LookupError::Empty);
+    }
+
+    let candidates = [
+        (num_normalized(input), MatchKind::Numeric),
+        (alpha3_normalized(input), MatchKind::Alpha3),
+        (index::name_folded(&folded), MatchKind::Name),
+        (index::alias(&folded), MatchKind::Alias),
+    ];
+    let mut matches: Vec<Match> = Vec::new();
+
+    for &(currency, kind) in &candidates {
+        if let Some(currency) = currency {
+            if !matches.iter().any(|m| m.currency.alpha3 == currency.alpha3) {
+                matches.push(Match { currency, kind });
+            }
+        }
+    }
+
+    match matches.len() {
+        0 => Err(LookupError::NotFound(input.trim().to_owned())),
+        1 => Ok(matches.remove(0)),
+        _ => Err(LookupError::Conflict(matches.iter().map(|m| m.currency.alpha3).collect())),
+    }
+}
@@ -0,0 +1,177 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+//! Support for the `currency!` macro, which is not part of the public API.
+
+use core::str;
+
+use {CurrencyCode, index};
+
+/// Resolves a currency from an Alpha3 code string literal or a numeric code
+/// integer literal at compile time.
+///
+/// An unknown code fails compilation, suggesting the closest known code:
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate iso4217;
+///
+/// use iso4217::CurrencyCode;
+///
+/// const BASE: &CurrencyCode = currency!("EUR");
+///
+/// fn main() {
+///     assert_eq!(BASE.num, "978");
+///     assert_eq!(currency!(840).alpha3, "USD");
+///     assert_eq!(currency!(008).alpha3, "ALL");
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate iso4217;
+///
+/// fn main() {
+///     // error: unknown currency code "EUD", did you mean "EUR"?
+///     let base = currency!("EUD");
+/// }
+/// ```
+#[macro_export]
+macro_rules! currency {
+    ($code:literal) => {{
+        const CURRENCY: &'static $crate::CurrencyCode = match $crate::macros::resolve(stringify!($code)) {
+            Ok(currency) => currency,
+            Err(ref message) => panic!("{}", message.as_str()),
+        };
+
+        CURRENCY
+    }};
+}
+
+/// An error message built at compile time, as `panic!` can't format one in
+/// constants.
+pub struct Message {
+    bytes: [u8; 96],
+    len: usize,
+}
+
+impl Message {
+    const fn new() -> Message {
+        Message {
+            bytes: [0; 96],
+            len: 0,
+        }
+    }
+
+    const fn push(mut self, text: &str) -> Message {
+        let text = text.as_bytes();
+        let mut i = 0;
+
+        while i < text.len() && self.len < self.bytes.len() {
+            self.bytes[self.len] = text[i];
+            self.len += 1;
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Returns the message.
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.bytes.split_at(self.len);
+
+        match ::core::str::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(_) => "unknown currency code",
+        }
+    }
+}
+
+/// Resolves the source text of a literal, as given by `stringify!`, to a
+/// currency.
+pub const fn resolve(literal: &str) -> Result<&'static CurrencyCode, Message> {
+    let bytes = literal.as_bytes();
+    let quoted = bytes.len() >= 2 && bytes[0] == b'"' && bytes[bytes.len() - 1] == b'"';
+
+    let code = if quoted { bytes.split_at(1).1.split_at(bytes.len() - 2).0 } else { bytes };
+
+    let found = if quoted {
+        match str::from_utf8(code) {
+            Ok(code) => index::alpha3(code),
+            Err(_) => None,
+        }
+    } else {
+        match number(bytes) {
+            Some(num) => index::num_value(num),
+            None => {
+                let message = Message::new().push("expected an Alpha3 code string or a numeric code, found ");
+
+                return Err(message.push(literal));
+            },
+        }
+    };
+
+    if let Some(currency) = found {
+        return Ok(currency);
+    }
+
+    let message = Message::new().push("unknown currency code ").push(literal);
+    let nearest = if quoted {
+        index::nearest_alpha3(code)
+    } else {
+        match number(bytes) {
+            Some(num) => index::nearest_num(num),
+            None => None,
+        }
+    };
+
+    match nearest {
+        Some(code) if quoted => Err(message.push(", did you mean \"").push(code.alpha3).push("\"?")),
+        Some(code) => Err(message.push(", did you mean ").push(code.num).push("?")),
+        None => Err(message),
+    }
+}
+
+/// Reads a decimal integer literal without a suffix.
+const fn number(bytes: &[u8]) -> Option<u16> {
+    if bytes.is_empty() || bytes.len() > 4 {
+        return None;
+    }
+
+    let mut num = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+
+        num = num * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Some(num)
+}
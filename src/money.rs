@@ -0,0 +1,164 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use CurrencyCode;
+
+/// Strategy used when an exact result has to be rounded to the minor unit of
+/// a currency.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round towards zero, discarding the remainder.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round to the nearest unit, with ties rounded away from zero.
+    HalfUp,
+    /// Round to the nearest unit, with ties rounded towards zero.
+    HalfDown,
+    /// Round to the nearest unit, with ties rounded to the even neighbour
+    /// (banker's rounding).
+    HalfEven,
+}
+
+/// An amount of money, stored as an integer number of minor units of its
+/// currency.
+///
+/// The minor unit is defined by the currency's `exp`: an amount of `1234` is
+/// 12.34 USD (`exp` 2), 1234 JPY (`exp` 0) or 1.234 BHD (`exp` 3).
+#[derive(Clone, Copy, Debug)]
+pub struct Money {
+    /// Amount in minor units of the currency
+    pub amount: i64,
+    /// Currency the amount is denominated in
+    pub currency: &'static CurrencyCode,
+}
+
+impl Money {
+    /// Creates an amount of money from a number of minor units.
+    pub fn new(amount: i64, currency: &'static CurrencyCode) -> Money {
+        Money { amount, currency }
+    }
+
+    /// Creates a zero amount in the given currency.
+    pub fn zero(currency: &'static CurrencyCode) -> Money {
+        Money::new(0, currency)
+    }
+
+    /// Returns whether the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Multiplies the amount by `numerator / denominator`, rounding the exact
+    /// result to the currency's minor unit.
+    ///
+    /// Returns `None` if the denominator is zero or the result does not fit
+    /// in an `i64`.
+    pub fn mul_ratio(&self, numerator: i64, denominator: i64, mode: RoundingMode) -> Option<Money> {
+        let product = i128::from(self.amount) * i128::from(numerator);
+        let amount = div_round(product, i128::from(denominator), mode)?;
+
+        to_i64(amount).map(|amount| Money::new(amount, self.currency))
+    }
+
+    /// Multiplies the amount by a percentage given as `value / 10^scale`
+    /// percent, e.g. `mul_percent(725, 2, mode)` applies 7.25%.
+    ///
+    /// Returns `None` if the result does not fit in an `i64`.
+    pub fn mul_percent(&self, value: i64, scale: u32, mode: RoundingMode) -> Option<Money> {
+        let denominator = 10i64.checked_pow(scale)?.checked_mul(100)?;
+
+        self.mul_ratio(value, denominator, mode)
+    }
+
+    /// Multiplies the amount by a number of basis points (hundredths of a
+    /// percent).
+    ///
+    /// Returns `None` if the result does not fit in an `i64`.
+    pub fn mul_basis_points(&self, basis_points: i64, mode: RoundingMode) -> Option<Money> {
+        self.mul_ratio(basis_points, 10_000, mode)
+    }
+}
+
+/// Divides `numerator` by `denominator`, rounding the quotient with `mode`.
+///
+/// Returns `None` on division by zero or overflow.
+pub(crate) fn div_round(numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator % denominator;
+
+    if remainder == 0 {
+        return Some(quotient);
+    }
+
+    // Sign of the exact result, used to step the truncated quotient away from
+    // zero.
+    let step = if (numerator < 0) == (denominator < 0) { 1 } else { -1 };
+    let away = match mode {
+        RoundingMode::Down => false,
+        RoundingMode::Up => true,
+        RoundingMode::Floor => step < 0,
+        RoundingMode::Ceiling => step > 0,
+        RoundingMode::HalfUp | RoundingMode::HalfDown | RoundingMode::HalfEven => {
+            let twice = remainder.unsigned_abs().checked_mul(2)?;
+            let denominator = denominator.unsigned_abs();
+
+            if twice > denominator {
+                true
+            } else if twice < denominator {
+                false
+            } else {
+                match mode {
+                    RoundingMode::HalfUp => true,
+                    RoundingMode::HalfDown => false,
+                    _ => quotient % 2 != 0,
+                }
+            }
+        },
+    };
+
+    if away {
+        quotient.checked_add(step)
+    } else {
+        Some(quotient)
+    }
+}
+
+fn to_i64(value: i128) -> Option<i64> {
+    if value < i128::from(i64::MIN) || value > i128::from(i64::MAX) {
+        None
+    } else {
+        Some(value as i64)
+    }
+}
@@ -0,0 +1,193 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::string::String;
+
+/// Base letters of U+00C0 to U+017F, with `.` for characters that aren't a
+/// Latin letter with diacritics.
+const LATIN_1_AND_EXTENDED_A: &str = concat!(
+    "AAAAAA.CEEEEIIIIDNOOOOO.OUUUUY..aaaaaa.ceeeeiiiidnooooo.ouuuuy.y",
+    "AaAaAaCcCcCcCcDdDdEeEeEeEeEeGgGgGgGgHhHhIiIiIiIiIi..JjKkkLlLlLlL",
+    "lLlNnNnNnn..OoOoOo..RrRrRrSsSsSsSsTtTtTtUuUuUuUuUuUuWwYyYZzZzZz.",
+);
+
+/// Base letters of U+1E00 to U+1EFF, Latin Extended Additional.
+const LATIN_EXTENDED_ADDITIONAL: &str = concat!(
+    "AaBbBbBbCcDdDdDdDdDdEeEeEeEeEeFfGgHhHhHhHhHhIiIiKkKkKkLlLlLlLlMm",
+    "MmMmNnNnNnNnOoOoOoOoPpPpRrRrRrRrSsSsSsSsSsTtTtTtTtUuUuUuUuUuVvVv",
+    "WwWwWwWwWwXxXxYyZzZzZzhtwy......AaAaAaAaAaAaAaAaAaAaAaAaEeEeEeEe",
+    "EeEeEeEeIiIiOoOoOoOoOoOoOoOoOoOoOoOoUuUuUuUuUuUuUuYyYyYyYy......",
+);
+
+/// Folds a string for loose comparison.
+///
+/// The result is lowercase, with diacritics removed (`złoty` and `zloty`
+/// both fold to `zloty`, whether the accents are precomposed or combining),
+/// fullwidth forms replaced by ASCII, typographic apostrophes replaced by
+/// `'`, and whitespace trimmed and collapsed to single spaces. This covers
+/// what NFC and NFKC normalization followed by accent stripping would do for
+/// Latin text, which is all currency names are written in.
+pub(crate) fn fold(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut space = false;
+
+    for c in s.chars() {
+        if c.is_whitespace() {
+            space = !out.is_empty();
+            continue;
+        }
+
+        if space {
+            out.push(' ');
+            space = false;
+        }
+
+        match c {
+            // Combining diacritical marks.
+            '\u{300}'..='\u{36f}' => {},
+            '\u{2018}' | '\u{2019}' | '\u{2bc}' | '`' => out.push('\''),
+            'Æ' | 'æ' => out.push_str("ae"),
+            'Œ' | 'œ' => out.push_str("oe"),
+            'Ĳ' | 'ĳ' => out.push_str("ij"),
+            'Þ' | 'þ' => out.push_str("th"),
+            'ß' => out.push_str("ss"),
+            'ſ' => out.push('s'),
+            '\u{ff01}'..='\u{ff5e}' => out.extend(char::from_u32(c as u32 - 0xfee0).map(|c| c.to_ascii_lowercase())),
+            '\u{c0}'..='\u{17f}' => push_base(&mut out, c, LATIN_1_AND_EXTENDED_A, 0xc0),
+            '\u{1e00}'..='\u{1eff}' => push_base(&mut out, c, LATIN_EXTENDED_ADDITIONAL, 0x1e00),
+            c => out.extend(c.to_lowercase()),
+        }
+    }
+
+    out
+}
+
+fn push_base(out: &mut String, c: char, table: &str, first: u32) {
+    match table.as_bytes()[(c as u32 - first) as usize] {
+        b'.' => out.extend(c.to_lowercase()),
+        base => out.push(char::from(base).to_ascii_lowercase()),
+    }
+}
+
+/// The longest folded name `fold_latin` can hold, in bytes.
+pub(crate) const MAX_FOLDED: usize = 64;
+
+/// Folds a name written in Latin letters like `fold`, without allocating, so
+/// that names can be folded at compile time.
+///
+/// Returns the folded bytes and their length, or `None` if the name has a
+/// character that doesn't fold to ASCII or is longer than `MAX_FOLDED`
+/// bytes once folded.
+pub(crate) const fn fold_latin(s: &str) -> Option<([u8; MAX_FOLDED], usize)> {
+    let bytes = s.as_bytes();
+    let mut out = [0; MAX_FOLDED];
+    let mut len = 0;
+    let mut space = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let (c, width) = decode(bytes, i);
+        i += width;
+
+        // The characters `char::is_whitespace` accepts, which isn't const.
+        if let '\t'..='\r' | ' ' | '\u{85}' | '\u{a0}' | '\u{1680}' | '\u{2000}'..='\u{200a}' | '\u{2028}' | '\u{2029}' | '\u{202f}' | '\u{205f}' | '\u{3000}' = c {
+            space = len > 0;
+            continue;
+        }
+
+        let (folded, count) = match fold_char(c) {
+            Some(folded) => folded,
+            None => return None,
+        };
+
+        if len + count + space as usize > MAX_FOLDED {
+            return None;
+        }
+
+        if space {
+            out[len] = b' ';
+            len += 1;
+            space = false;
+        }
+
+        let mut j = 0;
+
+        while j < count {
+            out[len] = folded[j];
+            len += 1;
+            j += 1;
+        }
+    }
+
+    Some((out, len))
+}
+
+/// Folds a character like `fold`, to at most two ASCII bytes.
+const fn fold_char(c: char) -> Option<([u8; 2], usize)> {
+    let folded = match c {
+        '\u{300}'..='\u{36f}' => return Some(([0; 2], 0)),
+        '\u{2018}' | '\u{2019}' | '\u{2bc}' | '`' => b'\'',
+        'Æ' | 'æ' => return Some((*b"ae", 2)),
+        'Œ' | 'œ' => return Some((*b"oe", 2)),
+        'Ĳ' | 'ĳ' => return Some((*b"ij", 2)),
+        'Þ' | 'þ' => return Some((*b"th", 2)),
+        'ß' => return Some((*b"ss", 2)),
+        'ſ' => b's',
+        '\u{ff01}'..='\u{ff5e}' => (c as u32 - 0xfee0) as u8,
+        '\u{c0}'..='\u{17f}' => LATIN_1_AND_EXTENDED_A.as_bytes()[(c as u32 - 0xc0) as usize],
+        '\u{1e00}'..='\u{1eff}' => LATIN_EXTENDED_ADDITIONAL.as_bytes()[(c as u32 - 0x1e00) as usize],
+        '\0'..='\u{7f}' => c as u8,
+        _ => return None,
+    };
+
+    match folded {
+        b'.' if c as u32 >= 0x80 => None,
+        folded => Some(([folded.to_ascii_lowercase(), 0], 1)),
+    }
+}
+
+/// Decodes the character starting at byte `i` of a string, returning it with
+/// its length in bytes.
+const fn decode(bytes: &[u8], i: usize) -> (char, usize) {
+    let lead = bytes[i] as u32;
+    let (mut c, width) = match lead {
+        0x00..=0x7f => return (lead as u8 as char, 1),
+        0xc0..=0xdf => (lead & 0x1f, 2),
+        0xe0..=0xef => (lead & 0x0f, 3),
+        _ => (lead & 0x07, 4),
+    };
+    let mut j = 1;
+
+    while j < width {
+        c = c << 6 | (bytes[i + j] & 0x3f) as u32;
+        j += 1;
+    }
+
+    match char::from_u32(c) {
+        Some(c) => (c, width),
+        None => (char::REPLACEMENT_CHARACTER, width),
+    }
+}
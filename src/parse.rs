@@ -0,0 +1,316 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::FromStr;
+
+use {CurrencyCode, Locale, Money, NumberingSystem, all, alpha3};
+
+/// An error returned when a string can't be parsed into an amount of money.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseMoneyError {
+    /// The string holds no digits.
+    Empty,
+    /// No currency code, symbol or name was found next to the number.
+    MissingCurrency,
+    /// The text next to the number is not a known currency.
+    UnknownCurrency(String),
+    /// The currency symbol is used by several currencies; their Alpha3 codes
+    /// are given. A locale hint may resolve it.
+    AmbiguousCurrency(Vec<&'static str>),
+    /// A separator could be read either as a decimal or a grouping separator,
+    /// as in `1,234` for a currency with three decimals.
+    AmbiguousSeparator,
+    /// The number is malformed, or doesn't follow the locale's separators.
+    InvalidNumber,
+    /// The number has more decimals than the currency's `exp` allows.
+    TooManyDecimals {
+        /// Number of decimals the currency allows
+        exp: i8,
+        /// Number of decimals found
+        found: usize,
+    },
+    /// The amount does not fit in an `i64` of minor units.
+    Overflow,
+}
+
+impl Display for ParseMoneyError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            ParseMoneyError::Empty => f.write_str("no amount found"),
+            ParseMoneyError::MissingCurrency => f.write_str("no currency found"),
+            ParseMoneyError::UnknownCurrency(ref text) => write!(f, "unknown currency {:?}", text),
+            ParseMoneyError::AmbiguousCurrency(ref codes) => {
+                write!(f, "ambiguous currency, could be any of {}", codes.join(", "))
+            },
+            ParseMoneyError::AmbiguousSeparator => {
+                f.write_str("ambiguous separator, could be a decimal or grouping separator")
+            },
+            ParseMoneyError::InvalidNumber => f.write_str("invalid number"),
+            ParseMoneyError::TooManyDecimals { exp, found } => {
+                write!(f, "found {} decimals, the currency allows {}", found, exp)
+            },
+            ParseMoneyError::Overflow => f.write_str("amount too large"),
+        }
+    }
+}
+
+impl Error for ParseMoneyError {}
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+
+    /// Parses an amount without a locale hint. See
+    /// [`parse_money`](fn.parse_money.html).
+    fn from_str(s: &str) -> Result<Money, ParseMoneyError> {
+        parse_money(s, None)
+    }
+}
+
+/// Parses a localized amount of money such as `1.234,56 €`, `USD 1,234.56`,
+/// `¥1,200` or `-£3.50`.
+///
+/// The currency may be given by its Alpha3 code, a symbol or its name, before
+/// or after the number. Negative amounts are written with a minus sign or in
+/// parentheses.
+///
+/// Without a locale hint, the decimal separator is inferred: when both `.`
+/// and `,` appear the last one is the decimal separator, and a lone separator
+/// followed by three digits is only read as a decimal separator if the
+/// currency has three decimals, in which case the string is reported as
+/// ambiguous. With a hint, the locale's separators are required and its
+/// symbols take precedence, so `$` means CAD for `en-CA`.
+pub fn parse_money(input: &str, locale: Option<&Locale>) -> Result<Money, ParseMoneyError> {
+    let start = input.find(|c| digit_value(c).is_some()).ok_or(ParseMoneyError::Empty)?;
+    let end = input.rfind(|c| digit_value(c).is_some()).ok_or(ParseMoneyError::Empty)?;
+    let end = end + input[end..].chars().next().map_or(1, char::len_utf8);
+    let (prefix, number, suffix) = (&input[..start], &input[start..end], &input[end..]);
+
+    let negative = [prefix, suffix].iter().any(|affix| affix.contains(['-', '\u{2212}']))
+        || prefix.contains('(') && suffix.contains(')');
+    let text = match (strip_sign(prefix), strip_sign(suffix)) {
+        (prefix, ref suffix) if suffix.is_empty() && !prefix.is_empty() => prefix,
+        (ref prefix, suffix) if prefix.is_empty() && !suffix.is_empty() => suffix,
+        (ref prefix, _) if prefix.is_empty() => return Err(ParseMoneyError::MissingCurrency),
+        _ => return Err(ParseMoneyError::InvalidNumber),
+    };
+    let currency = currency(&text, locale)?;
+
+    let amount = amount(number, currency.exp, locale)?;
+    let amount = if negative { -amount } else { amount };
+
+    Ok(Money::new(amount, currency))
+}
+
+/// Returns the value of a decimal digit in any supported numbering system.
+pub(crate) fn digit_value(c: char) -> Option<u32> {
+    [
+        NumberingSystem::Latn,
+        NumberingSystem::Arab,
+        NumberingSystem::ArabExt,
+        NumberingSystem::Beng,
+        NumberingSystem::Deva,
+    ]
+    .iter()
+    .filter_map(|system| system.digits().iter().position(|&digit| digit == c))
+    .map(|value| value as u32)
+    .next()
+}
+
+/// Resolves a currency from its Alpha3 code, symbol or name.
+pub(crate) fn currency(text: &str, locale: Option<&Locale>) -> Result<&'static CurrencyCode, ParseMoneyError> {
+    if text.len() == 3 && text.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return alpha3(&text.to_ascii_uppercase()).ok_or_else(|| ParseMoneyError::UnknownCurrency(text.to_owned()));
+    }
+
+    let symbol = fold_width(text);
+    let matches = |locale: &Locale, currency: &CurrencyCode| fold_width(locale.symbol(currency)) == symbol;
+    let mut candidates = match locale {
+        Some(locale) => all().iter().filter(|c| matches(locale, c)).collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
+
+    if candidates.is_empty() {
+        candidates = all()
+            .iter()
+            .filter(|c| Locale::all().iter().any(|locale| matches(locale, c)))
+            .collect();
+    }
+
+    match candidates.len() {
+        0 => {},
+        1 => return Ok(candidates[0]),
+        _ => return Err(ParseMoneyError::AmbiguousCurrency(candidates.iter().map(|c| c.alpha3).collect())),
+    }
+
+    let name = text.to_lowercase();
+    let singular = name.trim_end_matches('s');
+
+    all()
+        .iter()
+        .find(|c| {
+            let candidate = c.name.to_lowercase();
+            candidate == name || candidate == singular
+        })
+        .ok_or_else(|| ParseMoneyError::UnknownCurrency(text.to_owned()))
+}
+
+/// Parses the digits and separators of an amount into minor units.
+pub(crate) fn amount(number: &str, exp: i8, locale: Option<&Locale>) -> Result<i64, ParseMoneyError> {
+    // Split into runs of digits and the single separators between them.
+    let mut runs = vec![String::new()];
+    let mut separators = Vec::new();
+
+    for c in number.chars() {
+        if let Some(value) = digit_value(c) {
+            let run = runs.last_mut().expect("there is always a run");
+            run.extend(char::from_digit(value, 10));
+        } else if runs.last().is_some_and(String::is_empty) {
+            return Err(ParseMoneyError::InvalidNumber);
+        } else {
+            separators.push(c);
+            runs.push(String::new());
+        }
+    }
+
+    let decimal = match locale {
+        Some(locale) => decimal_for_locale(&separators, locale)?,
+        None => infer_decimal(&runs, &separators, exp)?,
+    };
+    let (integer, fraction) = match decimal {
+        Some(idx) => (&runs[..=idx], runs[idx + 1].as_str()),
+        None => (&runs[..], ""),
+    };
+
+    if !valid_grouping(integer) {
+        return Err(ParseMoneyError::InvalidNumber);
+    }
+
+    let exp = exp.max(0);
+    let significant = fraction.trim_end_matches('0');
+
+    if significant.len() > exp as usize {
+        return Err(ParseMoneyError::TooManyDecimals { exp, found: fraction.len() });
+    }
+
+    let digits = integer.concat() + significant + &"0".repeat(exp as usize - significant.len());
+
+    digits.parse().map_err(|_| ParseMoneyError::Overflow)
+}
+
+/// Returns the index of the decimal separator using a locale's separators.
+fn decimal_for_locale(separators: &[char], locale: &Locale) -> Result<Option<usize>, ParseMoneyError> {
+    let mut decimal = None;
+
+    for (idx, &c) in separators.iter().enumerate() {
+        if locale.decimal.contains(c) && decimal.is_none() {
+            decimal = Some(idx);
+        } else if !(locale.group.contains(c) || is_group_only(c)) || decimal.is_some() {
+            return Err(ParseMoneyError::InvalidNumber);
+        }
+    }
+
+    Ok(decimal)
+}
+
+/// Returns the index of the decimal separator, inferred from the separators
+/// used and the currency's number of decimals.
+fn infer_decimal(runs: &[String], separators: &[char], exp: i8) -> Result<Option<usize>, ParseMoneyError> {
+    let last = match separators.last() {
+        Some(&last) if !is_group_only(last) => last,
+        _ => return Ok(None),
+    };
+    let idx = separators.len() - 1;
+
+    if last == '\u{66b}' {
+        return Ok(Some(idx));
+    }
+
+    // With both `.` and `,`, the last one is the decimal separator.
+    if separators.iter().any(|&c| !is_group_only(c) && c != last) {
+        return Ok(Some(idx));
+    }
+
+    // A separator used several times groups digits.
+    if separators.iter().filter(|&&c| c == last).count() > 1 {
+        return Ok(None);
+    }
+
+    let as_group = valid_grouping(runs);
+    let as_decimal = runs[idx + 1].len() <= exp.max(0) as usize;
+
+    match (as_group, as_decimal) {
+        (true, true) => Err(ParseMoneyError::AmbiguousSeparator),
+        (true, false) => Ok(None),
+        _ => Ok(Some(idx)),
+    }
+}
+
+/// Returns whether digit runs split by grouping separators are well formed:
+/// the last group has three digits and the ones before it two or three, to
+/// allow for Indian grouping.
+fn valid_grouping(runs: &[String]) -> bool {
+    match runs.split_last() {
+        Some((_, [])) => true,
+        Some((last, rest)) => {
+            last.len() == 3
+                && (1..=3).contains(&rest[0].len())
+                && rest[1..].iter().all(|run| run.len() == 2 || run.len() == 3)
+        },
+        None => false,
+    }
+}
+
+/// Removes signs, parentheses and direction marks around a currency.
+fn strip_sign(affix: &str) -> String {
+    affix
+        .chars()
+        .filter(|&c| !"()-+\u{2212}\u{200e}\u{200f}\u{61c}".contains(c))
+        .collect::<String>()
+        .trim()
+        .to_owned()
+}
+
+fn is_group_only(c: char) -> bool {
+    c.is_whitespace() || "'’\u{66c}".contains(c)
+}
+
+/// Maps fullwidth forms, such as `￥`, to their regular equivalents.
+fn fold_width(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+            '\u{ffe0}' => '¢',
+            '\u{ffe1}' => '£',
+            '\u{ffe5}' => '¥',
+            c => c,
+        })
+        .collect()
+}
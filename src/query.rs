@@ -0,0 +1,314 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use core::cmp::Ordering;
+
+use codes::ALL;
+use countries::{self, Region};
+use index::compare;
+use withdrawn::WITHDRAWN;
+use CurrencyCode;
+
+const TOTAL: usize = ALL.len() + WITHDRAWN.len();
+
+static BY_ALPHA3: [u8; TOTAL] = order(SortBy::Alpha3);
+static BY_NAME: [u8; TOTAL] = order(SortBy::Name);
+static BY_NUM: [u8; TOTAL] = order(SortBy::Num);
+
+/// Whether a currency belongs to one country or is shared by a monetary
+/// union.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Kind {
+    /// A currency issued for a single country, even if others adopted it, as
+    /// the United States dollar
+    National,
+    /// A currency issued for the members of a monetary union, as the euro or
+    /// the CFA francs
+    Supranational,
+}
+
+impl Kind {
+    /// Returns the kind of a currency. Supranational currencies are the euro
+    /// and those with Alpha3 codes starting with X.
+    pub fn of(currency: &CurrencyCode) -> Kind {
+        if currency.alpha3 == "EUR" || currency.alpha3.starts_with('X') {
+            Kind::Supranational
+        } else {
+            Kind::National
+        }
+    }
+}
+
+/// Whether a currency is in use or has been withdrawn.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Status {
+    /// A currency returned by [`all`](fn.all.html)
+    Active,
+    /// A currency returned by [`withdrawn`](fn.withdrawn.html)
+    Withdrawn,
+}
+
+/// The order in which a [`Query`](struct.Query.html) returns currencies.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SortBy {
+    /// By Alpha3 code
+    Alpha3,
+    /// By name
+    Name,
+    /// By numeric code
+    Num,
+}
+
+/// A query over the currencies, combining filters on their fields.
+///
+/// Every filter must match for a currency to be returned. Only active
+/// currencies are returned unless another `status` is asked for. Iterating
+/// allocates nothing, as the sort orders are computed at compile time.
+///
+/// ```rust
+/// use iso4217::{Kind, Query};
+///
+/// // Active national currencies with 3 decimals used in more than one
+/// // country.
+/// let query = Query::new().kind(Kind::National).exp(3).min_countries(2);
+///
+/// for currency in query {
+///     println!("{}", currency);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Query<'a> {
+    country: Option<&'a str>,
+    exp: Option<i8>,
+    kind: Option<Kind>,
+    limit: Option<usize>,
+    min_countries: usize,
+    name: Option<&'a str>,
+    region: Option<Region>,
+    sort: SortBy,
+    status: Status,
+}
+
+impl<'a> Query<'a> {
+    /// Creates a query matching every active currency, sorted by numeric
+    /// code.
+    pub fn new() -> Query<'a> {
+        Query {
+            country: None,
+            exp: None,
+            kind: None,
+            limit: None,
+            min_countries: 0,
+            name: None,
+            region: None,
+            sort: SortBy::Num,
+            status: Status::Active,
+        }
+    }
+
+    /// Matches currencies used by the country with the given Alpha2 code.
+    pub fn country(mut self, country: &'a str) -> Query<'a> {
+        self.country = Some(country);
+
+        self
+    }
+
+    /// Matches currencies with the given number of decimals.
+    pub fn exp(mut self, exp: i8) -> Query<'a> {
+        self.exp = Some(exp);
+
+        self
+    }
+
+    /// Matches currencies of the given kind.
+    pub fn kind(mut self, kind: Kind) -> Query<'a> {
+        self.kind = Some(kind);
+
+        self
+    }
+
+    /// Returns at most `limit` currencies.
+    pub fn limit(mut self, limit: usize) -> Query<'a> {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Matches currencies used in at least `count` countries.
+    pub fn min_countries(mut self, count: usize) -> Query<'a> {
+        self.min_countries = count;
+
+        self
+    }
+
+    /// Matches currencies whose name contains `text`, ignoring ASCII case.
+    pub fn name_contains(mut self, text: &'a str) -> Query<'a> {
+        self.name = Some(text);
+
+        self
+    }
+
+    /// Matches currencies used in at least one country of the region.
+    pub fn region(mut self, region: Region) -> Query<'a> {
+        self.region = Some(region);
+
+        self
+    }
+
+    /// Sets the order currencies are returned in.
+    pub fn sort_by(mut self, sort: SortBy) -> Query<'a> {
+        self.sort = sort;
+
+        self
+    }
+
+    /// Matches currencies with the given status, instead of active ones.
+    pub fn status(mut self, status: Status) -> Query<'a> {
+        self.status = status;
+
+        self
+    }
+
+    /// Returns an iterator over the matching currencies.
+    pub fn iter(&self) -> QueryIter<'a> {
+        let order = match self.sort {
+            SortBy::Alpha3 => &BY_ALPHA3,
+            SortBy::Name => &BY_NAME,
+            SortBy::Num => &BY_NUM,
+        };
+
+        QueryIter {
+            order: order.iter(),
+            query: *self,
+            remaining: self.limit.unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Returns whether a currency matches every filter except its status.
+    pub fn matches(&self, currency: &CurrencyCode) -> bool {
+        self.country.is_none_or(|country| currency.countries.contains(&country))
+            && self.exp.is_none_or(|exp| currency.exp == exp)
+            && self.kind.is_none_or(|kind| Kind::of(currency) == kind)
+            && currency.countries.len() >= self.min_countries
+            && self.name.is_none_or(|name| contains_ignore_case(currency.name, name))
+            && self.region.is_none_or(|region| {
+                currency.countries.iter().any(|&code| countries::region(code) == Some(region))
+            })
+    }
+}
+
+impl<'a> Default for Query<'a> {
+    fn default() -> Query<'a> {
+        Query::new()
+    }
+}
+
+impl<'a> IntoIterator for Query<'a> {
+    type Item = &'static CurrencyCode;
+    type IntoIter = QueryIter<'a>;
+
+    fn into_iter(self) -> QueryIter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the currencies matching a [`Query`](struct.Query.html).
+#[derive(Clone, Debug)]
+pub struct QueryIter<'a> {
+    order: ::core::slice::Iter<'static, u8>,
+    query: Query<'a>,
+    remaining: usize,
+}
+
+impl<'a> Iterator for QueryIter<'a> {
+    type Item = &'static CurrencyCode;
+
+    fn next(&mut self) -> Option<&'static CurrencyCode> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let status = self.query.status;
+        let query = self.query;
+        let currency = self
+            .order
+            .by_ref()
+            .map(|&index| index as usize)
+            .filter(|&index| (index < ALL.len()) == (status == Status::Active))
+            .map(entry)
+            .find(|currency| query.matches(currency))?;
+
+        self.remaining -= 1;
+
+        Some(currency)
+    }
+}
+
+const fn entry(index: usize) -> &'static CurrencyCode {
+    if index < ALL.len() {
+        &ALL[index]
+    } else {
+        &WITHDRAWN[index - ALL.len()].currency
+    }
+}
+
+/// Returns the positions of all currencies, active then withdrawn, sorted by
+/// a field.
+const fn order(sort: SortBy) -> [u8; TOTAL] {
+    let mut order = [0; TOTAL];
+    let mut i = 0;
+
+    while i < TOTAL {
+        let current = key(entry(i), sort);
+        let mut slot = i;
+
+        while slot > 0 && matches!(compare(key(entry(order[slot - 1] as usize), sort), current), Ordering::Greater) {
+            order[slot] = order[slot - 1];
+            slot -= 1;
+        }
+
+        order[slot] = i as u8;
+        i += 1;
+    }
+
+    order
+}
+
+const fn key(currency: &CurrencyCode, sort: SortBy) -> &[u8] {
+    match sort {
+        SortBy::Alpha3 => currency.alpha3.as_bytes(),
+        SortBy::Name => currency.name.as_bytes(),
+        SortBy::Num => currency.num.as_bytes(),
+    }
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    needle.is_empty()
+        || haystack
+            .as_bytes()
+            .windows(needle.len())
+            .any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
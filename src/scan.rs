@@ -0,0 +1,219 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use parse::{self, ParseMoneyError};
+use {CurrencyCode, Locale, Money};
+
+/// An amount of money found in a block of text.
+#[derive(Clone, Debug)]
+pub struct Mention {
+    /// Byte range of the mention in the text, covering the currency and the
+    /// number
+    pub span: Range<usize>,
+    /// The amount found
+    pub money: Money,
+    /// How likely the mention is to be correct, from 0 to 1
+    pub confidence: f32,
+}
+
+/// Finds every amount with a currency in a block of text, such as
+/// `Total due: EUR 1 204,00 incl. VAT`.
+///
+/// Currencies are recognised by their Alpha3 code, symbol or name, written
+/// before or after the number. Numbers without a currency are ignored.
+///
+/// The confidence of a mention is lowered when the currency symbol is shared
+/// by several currencies, in which case the most common one is assumed (`$`
+/// is read as USD), when the code is not in upper case, and when a separator
+/// could be read either as a decimal or a grouping separator, in which case
+/// it is assumed to group digits.
+pub fn scan(text: &str) -> Vec<Mention> {
+    let mut mentions = Vec::new();
+    let mut pos = 0;
+
+    while let Some(number) = next_number(text, pos) {
+        pos = number.end;
+
+        if mentions.last().is_some_and(|m: &Mention| m.span.end > number.start) {
+            continue;
+        }
+
+        let before = currency_before(text, number.start);
+        let after = currency_after(text, number.end);
+        let found = before.into_iter().chain(after).filter_map(|(span, currency, confidence)| {
+            let (amount, certainty) = amount(&text[number.clone()], currency)?;
+            let start = span.start.min(number.start);
+            let end = span.end.max(number.end);
+
+            Some((start..end, amount, currency, confidence * certainty))
+        });
+
+        if let Some((span, amount, currency, confidence)) = found.max_by(|a, b| a.3.total_cmp(&b.3)) {
+            let signed = |idx: usize| text[..idx].ends_with(['-', '\u{2212}']);
+            let negative = signed(span.start) || signed(number.start);
+            let span = match text[..span.start].chars().next_back() {
+                Some(sign) if signed(span.start) => span.start - sign.len_utf8()..span.end,
+                _ => span,
+            };
+
+            pos = span.end;
+            mentions.push(Mention {
+                span,
+                money: Money::new(if negative { -amount } else { amount }, currency),
+                confidence,
+            });
+        }
+    }
+
+    mentions
+}
+
+/// Returns the byte range of the next number at or after `from`.
+///
+/// Numbers may contain `.`, `,` and apostrophes between digits. Spaces are
+/// only taken as grouping separators when followed by exactly three digits.
+fn next_number(text: &str, from: usize) -> Option<Range<usize>> {
+    let bytes = text.as_bytes();
+    let start = (from..bytes.len()).find(|&idx| {
+        bytes[idx].is_ascii_digit() && (idx == 0 || !bytes[idx - 1].is_ascii_alphanumeric())
+    })?;
+    let mut end = start;
+
+    loop {
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        }
+
+        let rest = &text[end..];
+        let separator = match rest.chars().next() {
+            Some(c) if ".,'’".contains(c) => c.len_utf8(),
+            Some(c) if c == ' ' || c == '\u{a0}' || c == '\u{202f}' => {
+                let group = &rest[c.len_utf8()..];
+                let digits = group.bytes().take_while(u8::is_ascii_digit).count();
+
+                if digits != 3 {
+                    break;
+                }
+
+                c.len_utf8()
+            },
+            _ => break,
+        };
+
+        if !rest[separator..].starts_with(|c: char| c.is_ascii_digit()) {
+            break;
+        }
+
+        end += separator;
+    }
+
+    Some(start..end)
+}
+
+/// Looks for a currency code or symbol right before a number, optionally
+/// separated by a space.
+fn currency_before(text: &str, number: usize) -> Option<(Range<usize>, &'static CurrencyCode, f32)> {
+    let head = text[..number].trim_end_matches(['-', '\u{2212}']);
+    let head = head.strip_suffix([' ', '\u{a0}']).unwrap_or(head);
+    let start = head.rfind(|c: char| c.is_whitespace() || "(:;".contains(c)).map_or(0, |idx| {
+        idx + head[idx..].chars().next().map_or(1, char::len_utf8)
+    });
+    let word = head[start..].trim_start_matches(['-', '\u{2212}']);
+    let start = head.len() - word.len();
+
+    resolve(word).map(|(currency, confidence)| (start..head.len(), currency, confidence))
+}
+
+/// Looks for a currency code, symbol or name right after a number, optionally
+/// separated by a space. Names may span up to five words.
+fn currency_after(text: &str, number: usize) -> Option<(Range<usize>, &'static CurrencyCode, f32)> {
+    let tail = &text[number..];
+    let tail = tail.strip_prefix([' ', '\u{a0}']).unwrap_or(tail);
+    let offset = text.len() - tail.len();
+    let mut end = 0;
+    let mut best = None;
+
+    for _ in 0..5 {
+        let rest = &tail[end..];
+        let skip = rest.len() - rest.trim_start().len();
+
+        if end > 0 && (skip == 0 || !rest[..skip].chars().all(|c| c == ' ')) {
+            break;
+        }
+
+        let word = rest[skip..].find(|c: char| c.is_whitespace() || ".,;:!?)".contains(c)).unwrap_or(rest.len() - skip);
+
+        if word == 0 {
+            break;
+        }
+
+        end += skip + word;
+
+        if let Some((currency, confidence)) = resolve(&tail[..end]) {
+            best = Some((offset..offset + end, currency, confidence));
+        }
+    }
+
+    best
+}
+
+/// Resolves a candidate currency, returning how confident the match is.
+fn resolve(word: &str) -> Option<(&'static CurrencyCode, f32)> {
+    if word.is_empty() || word.bytes().any(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let is_code = word.len() == 3 && word.bytes().all(|b| b.is_ascii_alphabetic());
+
+    match parse::currency(word, None) {
+        Ok(currency) if is_code && word.bytes().all(|b| b.is_ascii_uppercase()) => Some((currency, 0.9)),
+        Ok(currency) if is_code => Some((currency, 0.4)),
+        Ok(currency) => Some((currency, 0.9)),
+        Err(ParseMoneyError::AmbiguousCurrency(_)) => {
+            let en = Locale::get("en")?;
+
+            parse::currency(word, Some(en)).ok().map(|currency| (currency, 0.5))
+        },
+        Err(_) => None,
+    }
+}
+
+/// Parses a number for a currency, returning the amount in minor units and
+/// how certain its interpretation is.
+fn amount(number: &str, currency: &CurrencyCode) -> Option<(i64, f32)> {
+    match parse::amount(number, currency.exp, None) {
+        Ok(amount) => Some((amount, 1.0)),
+        Err(ParseMoneyError::AmbiguousSeparator) => {
+            let grouped = number.replace([',', '.'], "");
+
+            parse::amount(&grouped, currency.exp, None).ok().map(|amount| (amount, 0.6))
+        },
+        Err(_) => None,
+    }
+}
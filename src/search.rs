@@ -0,0 +1,151 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use normalize::fold;
+use {CurrencyCode, all, countries};
+
+/// A currency found by [`search`](fn.search.html).
+#[derive(Clone, Debug)]
+pub struct SearchResult {
+    /// The currency found
+    pub currency: &'static CurrencyCode,
+    /// How well the currency matches the query, from 0 to 1
+    pub score: f32,
+}
+
+/// Searches currencies by Alpha3 code, numeric code, name and the names of
+/// the countries using them, returning at most `limit` results from best to
+/// worst match.
+///
+/// The query is compared ignoring case and diacritics. From best to worst, a
+/// currency scores for a field equal to the query, a field starting with it,
+/// every word of the query starting a word of the field (so "swis fr" finds
+/// the Swiss franc), some words of the query being words of the field, and
+/// words within a small edit distance of each other to allow for typos.
+/// Country names weigh less than the currency's own fields. Ties are ordered
+/// by Alpha3 code.
+pub fn search(query: &str, limit: usize) -> Vec<SearchResult> {
+    let query = fold(query);
+
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut results = all()
+        .iter()
+        .filter_map(|currency| {
+            let fields = [currency.alpha3, currency.num, currency.name];
+            let own = fields.iter().map(|field| score(&query, field));
+            let countries = currency
+                .countries
+                .iter()
+                .filter_map(|&alpha2| countries::name(alpha2))
+                .map(|name| score(&query, name) * 0.8);
+            let best = own.chain(countries).fold(0.0f32, f32::max);
+
+            if best > 0.0 {
+                Some(SearchResult { currency, score: best })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    results.sort_by(|a, b| {
+        b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal).then_with(|| a.currency.alpha3.cmp(b.currency.alpha3))
+    });
+    results.truncate(limit);
+
+    results
+}
+
+/// Scores how well a folded query matches a field.
+fn score(query: &str, field: &str) -> f32 {
+    let field = fold(field);
+
+    if field == query {
+        return 1.0;
+    }
+
+    if field.starts_with(query) {
+        return 0.9;
+    }
+
+    let query_words = query.split(' ').collect::<Vec<_>>();
+    let field_words = field.split([' ', '-', '/']).collect::<Vec<_>>();
+
+    if query_words.iter().all(|q| field_words.iter().any(|f| f.starts_with(q))) {
+        return 0.8;
+    }
+
+    let exact = query_words.iter().filter(|q| field_words.contains(q)).count();
+
+    if exact > 0 {
+        return 0.6 * exact as f32 / query_words.len() as f32;
+    }
+
+    // Average closeness of each query word to its nearest field word, for
+    // words within one edit, or two for words longer than four letters.
+    let mut total = 0.0;
+
+    for q in &query_words {
+        let len = q.chars().count();
+        let allowed = if len > 4 { 2 } else { 1 };
+        let nearest = field_words.iter().map(|f| distance(q, f)).min().unwrap_or(usize::MAX);
+
+        if len < 3 || nearest > allowed {
+            return 0.0;
+        }
+
+        total += 1.0 - nearest as f32 / len as f32;
+    }
+
+    0.5 * total / query_words.len() as f32
+}
+
+/// Returns the Levenshtein distance between two strings, counting a word
+/// prefix of `b` as a match so partially typed words aren't penalised.
+fn distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous.into_iter().min().unwrap_or(usize::MAX)
+}
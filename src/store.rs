@@ -0,0 +1,220 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::vec::Vec;
+use alloc::collections::BTreeMap;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::FromStr;
+
+use {CurrencyCode, ExchangeRate, Money, RoundingMode, alpha3, convert};
+
+/// A calendar date, used to tell when an exchange rate is valid.
+///
+/// Dates are ordered chronologically and parse from and display as ISO 8601
+/// (`YYYY-MM-DD`).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date, if the day exists in the given month and year.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Date> {
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+
+        if day == 0 || day > days {
+            return None;
+        }
+
+        Some(Date { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Date, ParseDateError> {
+        let bytes = s.as_bytes();
+        let digits = |range: &[u8]| range.iter().all(|b| b.is_ascii_digit());
+
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-'
+            || !digits(&bytes[..4]) || !digits(&bytes[5..7]) || !digits(&bytes[8..]) {
+            return Err(ParseDateError);
+        }
+
+        // All parts are ASCII digits of bounded length, so these can't fail.
+        let year = s[..4].parse().map_err(|_| ParseDateError)?;
+        let month = s[5..7].parse().map_err(|_| ParseDateError)?;
+        let day = s[8..].parse().map_err(|_| ParseDateError)?;
+
+        Date::new(year, month, day).ok_or(ParseDateError)
+    }
+}
+
+/// An error returned when a string is not a valid `YYYY-MM-DD` date.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseDateError;
+
+impl Display for ParseDateError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str("invalid date, expected YYYY-MM-DD")
+    }
+}
+
+impl Error for ParseDateError {}
+
+/// An in-memory history of exchange rates, queried by the date a rate was
+/// valid on.
+///
+/// Each rate is valid from the date it was inserted under until the next
+/// rate for the same pair. When no rate is stored for a pair, the store falls
+/// back to the inverse pair and then to cross rates through its pivot
+/// currencies, USD and EUR by default.
+#[derive(Clone, Debug)]
+pub struct RateStore {
+    pivots: Vec<&'static CurrencyCode>,
+    rates: BTreeMap<(&'static str, &'static str), BTreeMap<Date, ExchangeRate>>,
+}
+
+impl RateStore {
+    /// Creates an empty store, pivoting through USD and EUR.
+    pub fn new() -> RateStore {
+        RateStore {
+            pivots: ["USD", "EUR"].iter().filter_map(|code| alpha3(code)).collect(),
+            rates: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the currencies tried, in order, when deriving cross rates.
+    pub fn set_pivots(&mut self, pivots: &[&'static CurrencyCode]) {
+        self.pivots = pivots.to_vec();
+    }
+
+    /// Inserts a rate valid from the given date, replacing any rate for the
+    /// same pair and date.
+    pub fn insert(&mut self, date: Date, rate: ExchangeRate) {
+        self.rates
+            .entry((rate.base.alpha3, rate.quote.alpha3))
+            .or_default()
+            .insert(date, rate);
+    }
+
+    /// Returns the number of stored rates.
+    pub fn len(&self) -> usize {
+        self.rates.values().map(BTreeMap::len).sum()
+    }
+
+    /// Returns whether no rates are stored.
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// Returns the rate from `base` to `quote` valid on the given date: the
+    /// latest rate on or before it.
+    ///
+    /// Falls back to the inverse of the opposite pair and then to a cross
+    /// rate through a pivot currency.
+    pub fn rate(&self, base: &CurrencyCode, quote: &CurrencyCode, date: Date) -> Option<ExchangeRate> {
+        if let Some(rate) = self.direct(base, quote, date) {
+            return Some(rate);
+        }
+
+        self.pivots
+            .iter()
+            .filter(|pivot| pivot.alpha3 != base.alpha3 && pivot.alpha3 != quote.alpha3)
+            .filter_map(|pivot| {
+                let left = self.direct(base, pivot, date)?;
+                let right = self.direct(pivot, quote, date)?;
+
+                left.cross(&right)
+            })
+            .next()
+    }
+
+    /// Converts an amount into another currency at the rate valid on the
+    /// given date.
+    ///
+    /// Returns `None` if no rate is known or the result does not fit in an
+    /// `i64`.
+    pub fn convert(&self, money: Money, quote: &CurrencyCode, date: Date, mode: RoundingMode) -> Option<Money> {
+        convert(money, &self.rate(money.currency, quote, date)?, mode)
+    }
+
+    fn direct(&self, base: &CurrencyCode, quote: &CurrencyCode, date: Date) -> Option<ExchangeRate> {
+        let latest = |base: &str, quote: &str| {
+            self.rates
+                .get(&(base, quote))
+                .and_then(|history| history.range(..=date).next_back())
+                .map(|(_, rate)| *rate)
+        };
+
+        latest(base.alpha3, quote.alpha3).or_else(|| latest(quote.alpha3, base.alpha3).map(|rate| rate.inverse()))
+    }
+}
+
+impl Default for RateStore {
+    fn default() -> RateStore {
+        RateStore::new()
+    }
+}
+
+impl Extend<(Date, ExchangeRate)> for RateStore {
+    fn extend<I: IntoIterator<Item = (Date, ExchangeRate)>>(&mut self, iter: I) {
+        for (date, rate) in iter {
+            self.insert(date, rate);
+        }
+    }
+}
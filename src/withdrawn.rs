@@ -0,0 +1,451 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use core::cmp::Ordering;
+
+use index;
+use CurrencyCode;
+
+/// A withdrawn currency and the Alpha3 code of the currency that replaced it.
+pub(crate) struct Withdrawn {
+    pub(crate) currency: CurrencyCode,
+    pub(crate) replaced_by: &'static str,
+}
+
+/// Currencies withdrawn from ISO 4217 since the euro's introduction, sorted
+/// by Alpha3 code.
+///
+/// Currencies replaced by a code that was itself withdrawn, like the
+/// Yugoslav dinar, are left out. The ECU, a unit of account rather than the
+/// currency of a country, has no countries.
+pub(crate) static WITHDRAWN: [Withdrawn; 36] = sorted([
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "ADP",
+            countries: &["AD"],
+            exp: 0,
+            name: "Andorran peseta",
+            num: "020",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "AFA",
+            countries: &["AF"],
+            exp: 2,
+            name: "Afghan afghani (1925-2002)",
+            num: "004",
+        },
+        replaced_by: "AFN",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "ATS",
+            countries: &["AT"],
+            exp: 2,
+            name: "Austrian schilling",
+            num: "040",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "AZM",
+            countries: &["AZ"],
+            exp: 2,
+            name: "Azerbaijani manat (1992-2006)",
+            num: "031",
+        },
+        replaced_by: "AZN",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "BEF",
+            countries: &["BE"],
+            exp: 0,
+            name: "Belgian franc",
+            num: "056",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "BGL",
+            countries: &["BG"],
+            exp: 2,
+            name: "Bulgarian lev (1962-1999)",
+            num: "100",
+        },
+        replaced_by: "BGN",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "CSD",
+            countries: &["RS"],
+            exp: 2,
+            name: "Serbian dinar (2003-2006)",
+            num: "891",
+        },
+        replaced_by: "RSD",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "CYP",
+            countries: &["CY"],
+            exp: 2,
+            name: "Cypriot pound",
+            num: "196",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "DEM",
+            countries: &["DE"],
+            exp: 2,
+            name: "German mark",
+            num: "276",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "EEK",
+            countries: &["EE"],
+            exp: 2,
+            name: "Estonian kroon",
+            num: "233",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "ESP",
+            countries: &["ES", "AD"],
+            exp: 0,
+            name: "Spanish peseta",
+            num: "724",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "FIM",
+            countries: &["FI"],
+            exp: 2,
+            name: "Finnish markka",
+            num: "246",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "FRF",
+            countries: &["FR", "AD", "MC"],
+            exp: 2,
+            name: "French franc",
+            num: "250",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "GHC",
+            countries: &["GH"],
+            exp: 2,
+            name: "Ghanaian cedi (1967-2007)",
+            num: "288",
+        },
+        replaced_by: "GHS",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "GRD",
+            countries: &["GR"],
+            exp: 0,
+            name: "Greek drachma",
+            num: "300",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "IEP",
+            countries: &["IE"],
+            exp: 2,
+            name: "Irish pound",
+            num: "372",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "ITL",
+            countries: &["IT", "VA"],
+            exp: 0,
+            name: "Italian lira",
+            num: "380",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "LTL",
+            countries: &["LT"],
+            exp: 2,
+            name: "Lithuanian litas",
+            num: "440",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "LUF",
+            countries: &["LU"],
+            exp: 0,
+            name: "Luxembourg franc",
+            num: "442",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "LVL",
+            countries: &["LV"],
+            exp: 2,
+            name: "Latvian lats",
+            num: "428",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "MGF",
+            countries: &["MG"],
+            exp: 0,
+            name: "Malagasy franc",
+            num: "450",
+        },
+        replaced_by: "MGA",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "MTL",
+            countries: &["MT"],
+            exp: 2,
+            name: "Maltese lira",
+            num: "470",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "MZM",
+            countries: &["MZ"],
+            exp: 2,
+            name: "Mozambican metical (1980-2006)",
+            num: "508",
+        },
+        replaced_by: "MZN",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "NLG",
+            countries: &["NL"],
+            exp: 2,
+            name: "Dutch guilder",
+            num: "528",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "PTE",
+            countries: &["PT"],
+            exp: 0,
+            name: "Portuguese escudo",
+            num: "620",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "ROL",
+            countries: &["RO"],
+            exp: 2,
+            name: "Romanian leu (1952-2005)",
+            num: "642",
+        },
+        replaced_by: "RON",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "RUR",
+            countries: &["RU"],
+            exp: 2,
+            name: "Russian ruble (1992-1997)",
+            num: "810",
+        },
+        replaced_by: "RUB",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "SDD",
+            countries: &["SD"],
+            exp: 2,
+            name: "Sudanese dinar",
+            num: "736",
+        },
+        replaced_by: "SDG",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "SIT",
+            countries: &["SI"],
+            exp: 2,
+            name: "Slovenian tolar",
+            num: "705",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "SKK",
+            countries: &["SK"],
+            exp: 2,
+            name: "Slovak koruna",
+            num: "703",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "SRG",
+            countries: &["SR"],
+            exp: 2,
+            name: "Surinamese guilder",
+            num: "740",
+        },
+        replaced_by: "SRD",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "TMM",
+            countries: &["TM"],
+            exp: 2,
+            name: "Turkmenistani manat (1993-2008)",
+            num: "795",
+        },
+        replaced_by: "TMT",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "TRL",
+            countries: &["TR"],
+            exp: 0,
+            name: "Turkish lira (1923-2005)",
+            num: "792",
+        },
+        replaced_by: "TRY",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "VEB",
+            countries: &["VE"],
+            exp: 2,
+            name: "Venezuelan bolivar (1879-2008)",
+            num: "862",
+        },
+        replaced_by: "VEF",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "XEU",
+            countries: &[],
+            exp: 2,
+            name: "European Currency Unit",
+            num: "954",
+        },
+        replaced_by: "EUR",
+    },
+    Withdrawn {
+        currency: CurrencyCode {
+            alpha3: "ZMK",
+            countries: &["ZM"],
+            exp: 2,
+            name: "Zambian kwacha (1968-2012)",
+            num: "894",
+        },
+        replaced_by: "ZMW",
+    },
+]);
+
+/// Returns all currencies withdrawn from ISO 4217 since the euro's
+/// introduction, such as the German mark or the Turkish lira of 1923, sorted
+/// by Alpha3 code.
+///
+/// These are not returned by `all` or any lookup, but are reported by
+/// `ParseCurrencyError::Withdrawn` and can be listed through `Query`.
+pub fn withdrawn() -> impl ExactSizeIterator<Item = &'static CurrencyCode> {
+    WITHDRAWN.iter().map(|withdrawn| &withdrawn.currency)
+}
+
+/// Returns the withdrawn currency with the given Alpha3 code and the Alpha3
+/// code of its replacement.
+pub(crate) fn alpha3(alpha3: &str) -> Option<(&'static CurrencyCode, &'static str)> {
+    WITHDRAWN
+        .binary_search_by(|withdrawn| withdrawn.currency.alpha3.cmp(alpha3))
+        .ok()
+        .map(|index| (&WITHDRAWN[index].currency, WITHDRAWN[index].replaced_by))
+}
+
+/// Returns the withdrawn currency with the given numeric code and the Alpha3
+/// code of its replacement.
+pub(crate) fn num(num: &str) -> Option<(&'static CurrencyCode, &'static str)> {
+    WITHDRAWN
+        .iter()
+        .find(|withdrawn| withdrawn.currency.num == num)
+        .map(|withdrawn| (&withdrawn.currency, withdrawn.replaced_by))
+}
+
+/// Checks at compile time that the table is sorted by Alpha3 code, which
+/// `alpha3` relies on to search it by halves, and that every replacement is
+/// a current currency.
+const fn sorted(table: [Withdrawn; 36]) -> [Withdrawn; 36] {
+    let mut i = 0;
+
+    while i < table.len() {
+        assert!(index::alpha3(table[i].replaced_by).is_some(), "replacements must be current currencies");
+        assert!(
+            i == 0 || matches!(index::compare(table[i - 1].currency.alpha3.as_bytes(), table[i].currency.alpha3.as_bytes()), Ordering::Less),
+            "withdrawn currencies must be sorted by Alpha3 code"
+        );
+        i += 1;
+    }
+
+    table
+}
@@ -0,0 +1,727 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by zeyla on GitHub.
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use Money;
+
+/// A language amounts can be spelled out in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Language {
+    /// English, using the short scale and no "and" between hundreds and tens.
+    English,
+    /// French, with traditional spelling such as `quatre-vingts`.
+    French,
+    /// German.
+    German,
+    /// Spanish.
+    Spanish,
+}
+
+impl Language {
+    /// Returns the language of a tag such as `fr` or `es-MX`, if supported.
+    pub fn from_tag(tag: &str) -> Option<Language> {
+        let language = tag.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+
+        Some(match &*language {
+            "en" => Language::English,
+            "fr" => Language::French,
+            "de" => Language::German,
+            "es" => Language::Spanish,
+            _ => return None,
+        })
+    }
+
+    fn idx(self) -> usize {
+        match self {
+            Language::English => 0,
+            Language::French => 1,
+            Language::German => 2,
+            Language::Spanish => 3,
+        }
+    }
+}
+
+/// Spells out an amount of money, as written on cheques: the major units in
+/// words and the minor units in digits, e.g. "One thousand two hundred
+/// thirty-four US dollars and 56 cents".
+///
+/// Currencies with a zero `exp`, such as JPY, have no minor units and are
+/// spelled as a whole. The minor part is left out when it is zero. For
+/// currencies without translated unit names, the name of the currency is used
+/// and the minor units are written as a fraction, as in "and 56/100".
+pub fn amount_in_words(money: Money, lang: Language) -> String {
+    let currency = money.currency;
+    let exp = currency.exp.max(0) as u32;
+    let scale = 10u64.pow(exp);
+    let amount = money.amount.unsigned_abs();
+    let (major, minor) = (amount / scale, amount % scale);
+    let names = UNITS.iter().find(|&&(code, _)| code == currency.alpha3).map(|(_, names)| &names[lang.idx()]);
+    let feminine = names.is_some_and(|names| names.feminine);
+    let major_name = match names {
+        Some(names) if major == 1 || lang == Language::French && major == 0 => names.major.0,
+        Some(names) => names.major.1,
+        None => currency.name,
+    };
+
+    let mut out = String::new();
+
+    if money.amount < 0 {
+        out.push_str(["minus ", "moins ", "minus ", "menos "][lang.idx()]);
+    }
+
+    let words = match lang {
+        Language::English => english(major),
+        Language::French => french(major, feminine),
+        Language::German => german(major, feminine),
+        Language::Spanish => spanish(major, feminine),
+    };
+    out.push_str(&words);
+    out.push(' ');
+
+    // "un million de dollars", "un millón de dólares".
+    if (lang == Language::French || lang == Language::Spanish)
+        && [" million", " millions", " milliard", " milliards", " millón", " millones", " billón", " billones"]
+            .iter()
+            .any(|scale| words.ends_with(scale))
+    {
+        out.push_str(if lang == Language::French && starts_with_vowel(major_name) { "d'" } else { "de " });
+    }
+
+    out.push_str(major_name);
+
+    if minor > 0 {
+        out.push_str([" and ", " et ", " und ", " con "][lang.idx()]);
+
+        match names.and_then(|names| names.minor) {
+            Some((one, other)) => {
+                out.push_str(&format!("{} {}", minor, if minor == 1 { one } else { other }));
+            },
+            None => out.push_str(&format!("{:02$}/{}", minor, scale, exp as usize)),
+        }
+    }
+
+    capitalize(&out)
+}
+
+/// Names of the units of a currency in one language.
+struct UnitNames {
+    /// Singular and plural names of the major unit
+    major: (&'static str, &'static str),
+    /// Singular and plural names of the minor unit, if it has one
+    minor: Option<(&'static str, &'static str)>,
+    /// Whether the major unit is grammatically feminine
+    feminine: bool,
+}
+
+/// Unit names of currencies, in the order of `Language`.
+const UNITS: [(&str, [UnitNames; 4]); 11] = [
+    (
+        "AUD",
+        [
+            UnitNames {
+                major: ("Australian dollar", "Australian dollars"),
+                minor: Some(("cent", "cents")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("dollar australien", "dollars australiens"),
+                minor: Some(("cent", "cents")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("Australischer Dollar", "Australische Dollar"),
+                minor: Some(("Cent", "Cent")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("dólar australiano", "dólares australianos"),
+                minor: Some(("centavo", "centavos")),
+                feminine: false,
+            },
+        ],
+    ),
+    (
+        "CAD",
+        [
+            UnitNames {
+                major: ("Canadian dollar", "Canadian dollars"),
+                minor: Some(("cent", "cents")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("dollar canadien", "dollars canadiens"),
+                minor: Some(("cent", "cents")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("Kanadischer Dollar", "Kanadische Dollar"),
+                minor: Some(("Cent", "Cent")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("dólar canadiense", "dólares canadienses"),
+                minor: Some(("centavo", "centavos")),
+                feminine: false,
+            },
+        ],
+    ),
+    (
+        "CHF",
+        [
+            UnitNames {
+                major: ("Swiss franc", "Swiss francs"),
+                minor: Some(("centime", "centimes")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("franc suisse", "francs suisses"),
+                minor: Some(("centime", "centimes")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("Schweizer Franken", "Schweizer Franken"),
+                minor: Some(("Rappen", "Rappen")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("franco suizo", "francos suizos"),
+                minor: Some(("céntimo", "céntimos")),
+                feminine: false,
+            },
+        ],
+    ),
+    (
+        "CNY",
+        [
+            UnitNames {
+                major: ("Chinese yuan", "Chinese yuan"),
+                minor: Some(("fen", "fen")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("yuan", "yuans"),
+                minor: Some(("fen", "fen")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("Yuan", "Yuan"),
+                minor: Some(("Fen", "Fen")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("yuan", "yuanes"),
+                minor: Some(("fen", "fen")),
+                feminine: false,
+            },
+        ],
+    ),
+    (
+        "EUR",
+        [
+            UnitNames {
+                major: ("euro", "euros"),
+                minor: Some(("cent", "cents")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("euro", "euros"),
+                minor: Some(("centime", "centimes")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("Euro", "Euro"),
+                minor: Some(("Cent", "Cent")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("euro", "euros"),
+                minor: Some(("céntimo", "céntimos")),
+                feminine: false,
+            },
+        ],
+    ),
+    (
+        "GBP",
+        [
+            UnitNames {
+                major: ("pound sterling", "pounds sterling"),
+                minor: Some(("penny", "pence")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("livre sterling", "livres sterling"),
+                minor: Some(("penny", "pence")),
+                feminine: true,
+            },
+            UnitNames {
+                major: ("Pfund Sterling", "Pfund Sterling"),
+                minor: Some(("Penny", "Pence")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("libra esterlina", "libras esterlinas"),
+                minor: Some(("penique", "peniques")),
+                feminine: true,
+            },
+        ],
+    ),
+    (
+        "INR",
+        [
+            UnitNames {
+                major: ("Indian rupee", "Indian rupees"),
+                minor: Some(("paisa", "paise")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("roupie indienne", "roupies indiennes"),
+                minor: Some(("paisa", "paise")),
+                feminine: true,
+            },
+            UnitNames {
+                major: ("Indische Rupie", "Indische Rupien"),
+                minor: Some(("Paisa", "Paise")),
+                feminine: true,
+            },
+            UnitNames {
+                major: ("rupia india", "rupias indias"),
+                minor: Some(("paisa", "paisas")),
+                feminine: true,
+            },
+        ],
+    ),
+    (
+        "JPY",
+        [
+            UnitNames {
+                major: ("Japanese yen", "Japanese yen"),
+                minor: None,
+                feminine: false,
+            },
+            UnitNames {
+                major: ("yen", "yens"),
+                minor: None,
+                feminine: false,
+            },
+            UnitNames {
+                major: ("Yen", "Yen"),
+                minor: None,
+                feminine: false,
+            },
+            UnitNames {
+                major: ("yen", "yenes"),
+                minor: None,
+                feminine: false,
+            },
+        ],
+    ),
+    (
+        "MXN",
+        [
+            UnitNames {
+                major: ("Mexican peso", "Mexican pesos"),
+                minor: Some(("centavo", "centavos")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("peso mexicain", "pesos mexicains"),
+                minor: Some(("centavo", "centavos")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("Mexikanischer Peso", "Mexikanische Pesos"),
+                minor: Some(("Centavo", "Centavos")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("peso mexicano", "pesos mexicanos"),
+                minor: Some(("centavo", "centavos")),
+                feminine: false,
+            },
+        ],
+    ),
+    (
+        "SEK",
+        [
+            UnitNames {
+                major: ("Swedish krona", "Swedish kronor"),
+                minor: Some(("öre", "öre")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("couronne suédoise", "couronnes suédoises"),
+                minor: Some(("öre", "öre")),
+                feminine: true,
+            },
+            UnitNames {
+                major: ("Schwedische Krone", "Schwedische Kronen"),
+                minor: Some(("Öre", "Öre")),
+                feminine: true,
+            },
+            UnitNames {
+                major: ("corona sueca", "coronas suecas"),
+                minor: Some(("öre", "öre")),
+                feminine: true,
+            },
+        ],
+    ),
+    (
+        "USD",
+        [
+            UnitNames {
+                major: ("US dollar", "US dollars"),
+                minor: Some(("cent", "cents")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("dollar américain", "dollars américains"),
+                minor: Some(("cent", "cents")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("US-Dollar", "US-Dollar"),
+                minor: Some(("Cent", "Cent")),
+                feminine: false,
+            },
+            UnitNames {
+                major: ("dólar estadounidense", "dólares estadounidenses"),
+                minor: Some(("centavo", "centavos")),
+                feminine: false,
+            },
+        ],
+    ),
+];
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn starts_with_vowel(s: &str) -> bool {
+    s.starts_with(['a', 'e', 'i', 'o', 'u', 'y', 'é', 'h'])
+}
+
+/// Splits a number into groups of three digits, from the lowest one.
+fn groups(mut n: u64) -> Vec<u64> {
+    let mut groups = Vec::new();
+
+    while n > 0 {
+        groups.push(n % 1000);
+        n /= 1000;
+    }
+
+    groups
+}
+
+fn english(n: u64) -> String {
+    const ONES: [&str; 20] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve",
+        "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+    ];
+    const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+    const SCALES: [&str; 7] = ["", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion"];
+
+    if n == 0 {
+        return ONES[0].to_owned();
+    }
+
+    let below_thousand = |n: u64| {
+        let mut words = Vec::new();
+
+        if n >= 100 {
+            words.push(format!("{} hundred", ONES[(n / 100) as usize]));
+        }
+
+        match n % 100 {
+            0 => {},
+            rest @ 1..=19 => words.push(ONES[rest as usize].to_owned()),
+            rest if rest % 10 == 0 => words.push(TENS[(rest / 10) as usize].to_owned()),
+            rest => words.push(format!("{}-{}", TENS[(rest / 10) as usize], ONES[(rest % 10) as usize])),
+        }
+
+        words.join(" ")
+    };
+
+    let mut words = Vec::new();
+
+    for (scale, &group) in groups(n).iter().enumerate().rev().filter(|&(_, &group)| group > 0) {
+        words.push(below_thousand(group));
+
+        if scale > 0 {
+            words.push(SCALES[scale].to_owned());
+        }
+    }
+
+    words.join(" ")
+}
+
+fn french(n: u64, feminine: bool) -> String {
+    const ONES: [&str; 17] = [
+        "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze", "douze",
+        "treize", "quatorze", "quinze", "seize",
+    ];
+    const TENS: [&str; 7] = ["", "dix", "vingt", "trente", "quarante", "cinquante", "soixante"];
+    const SCALES: [(&str, &str); 7] = [
+        ("", ""),
+        ("mille", "mille"),
+        ("un million", "millions"),
+        ("un milliard", "milliards"),
+        ("un billion", "billions"),
+        ("un billiard", "billiards"),
+        ("un trillion", "trillions"),
+    ];
+
+    if n == 0 {
+        return ONES[0].to_owned();
+    }
+
+    // `last` tells whether nothing follows, for the plural of "vingts" and
+    // "cents".
+    let below_hundred = |n: u64, last: bool| -> String {
+        match n {
+            0..=16 => ONES[n as usize].to_owned(),
+            17..=19 => format!("dix-{}", ONES[(n - 10) as usize]),
+            20..=69 => match n % 10 {
+                0 => TENS[(n / 10) as usize].to_owned(),
+                1 => format!("{} et un", TENS[(n / 10) as usize]),
+                unit => format!("{}-{}", TENS[(n / 10) as usize], ONES[unit as usize]),
+            },
+            70..=79 if n == 71 => "soixante et onze".to_owned(),
+            70..=79 => format!("soixante-{}", french_teen(n - 60)),
+            80 if last => "quatre-vingts".to_owned(),
+            _ => format!("quatre-vingt-{}", french_teen(n - 80)).trim_end_matches('-').to_owned(),
+        }
+    };
+    let below_thousand = |n: u64, last: bool| -> String {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let mut words = Vec::new();
+
+        match hundreds {
+            0 => {},
+            1 => words.push("cent".to_owned()),
+            h if rest == 0 && last => words.push(format!("{} cents", ONES[h as usize])),
+            h => words.push(format!("{} cent", ONES[h as usize])),
+        }
+
+        if rest > 0 {
+            words.push(below_hundred(rest, last));
+        }
+
+        words.join(" ")
+    };
+
+    let mut words = Vec::new();
+
+    for (scale, &group) in groups(n).iter().enumerate().rev().filter(|&(_, &group)| group > 0) {
+        match scale {
+            0 => words.push(below_thousand(group, true)),
+            1 if group == 1 => words.push(SCALES[1].0.to_owned()),
+            1 => words.push(format!("{} {}", below_thousand(group, false), SCALES[1].1)),
+            _ if group == 1 => words.push(SCALES[scale].0.to_owned()),
+            _ => words.push(format!("{} {}", below_thousand(group, true), SCALES[scale].1)),
+        }
+    }
+
+    let words = words.join(" ");
+
+    if feminine && (words == "un" || words.ends_with(" un") || words.ends_with("-un")) {
+        words + "e"
+    } else {
+        words
+    }
+}
+
+/// Returns the French words for 0 to 19 as used after "soixante" and
+/// "quatre-vingt", with an empty string for zero.
+fn french_teen(n: u64) -> String {
+    match n {
+        0 => String::new(),
+        1 => "un".to_owned(),
+        2..=16 => ["", "", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze", "douze", "treize", "quatorze", "quinze", "seize"][n as usize].to_owned(),
+        _ => format!("dix-{}", french_teen(n - 10)),
+    }
+}
+
+fn german(n: u64, feminine: bool) -> String {
+    const ONES: [&str; 20] = [
+        "null", "ein", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun", "zehn", "elf", "zwölf",
+        "dreizehn", "vierzehn", "fünfzehn", "sechzehn", "siebzehn", "achtzehn", "neunzehn",
+    ];
+    const TENS: [&str; 10] = ["", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig"];
+    const SCALES: [(&str, &str); 7] = [
+        ("", ""),
+        ("tausend", "tausend"),
+        ("Million", "Millionen"),
+        ("Milliarde", "Milliarden"),
+        ("Billion", "Billionen"),
+        ("Billiarde", "Billiarden"),
+        ("Trillion", "Trillionen"),
+    ];
+
+    if n == 0 {
+        return ONES[0].to_owned();
+    }
+
+    let below_thousand = |n: u64| -> String {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let mut words = String::new();
+
+        if hundreds > 0 {
+            words.push_str(ONES[hundreds as usize]);
+            words.push_str("hundert");
+        }
+
+        match rest {
+            0 => {},
+            1..=19 => words.push_str(ONES[rest as usize]),
+            _ if rest % 10 == 0 => words.push_str(TENS[(rest / 10) as usize]),
+            _ => {
+                words.push_str(ONES[(rest % 10) as usize]);
+                words.push_str("und");
+                words.push_str(TENS[(rest / 10) as usize]);
+            },
+        }
+
+        words
+    };
+
+    // Everything below a million is written as a single word, larger scales
+    // as separate nouns: "zwei Millionen dreitausendvierhundert".
+    let mut words = Vec::new();
+    let mut small = String::new();
+
+    for (scale, &group) in groups(n).iter().enumerate().rev().filter(|&(_, &group)| group > 0) {
+        match scale {
+            0 => small.push_str(&below_thousand(group)),
+            1 => {
+                small.push_str(&below_thousand(group));
+                small.push_str(SCALES[1].0);
+            },
+            _ if group == 1 => words.push(format!("eine {}", SCALES[scale].0)),
+            _ => words.push(format!("{} {}", below_thousand(group), SCALES[scale].1)),
+        }
+    }
+
+    if feminine && (small == "ein" || small.ends_with("tausendein") || small.ends_with("hundertein")) {
+        small.push('e');
+    }
+
+    if !small.is_empty() {
+        words.push(small);
+    }
+
+    words.join(" ")
+}
+
+fn spanish(n: u64, feminine: bool) -> String {
+    const ONES: [&str; 30] = [
+        "cero", "un", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve", "diez", "once", "doce",
+        "trece", "catorce", "quince", "dieciséis", "diecisiete", "dieciocho", "diecinueve", "veinte", "veintiún",
+        "veintidós", "veintitrés", "veinticuatro", "veinticinco", "veintiséis", "veintisiete", "veintiocho",
+        "veintinueve",
+    ];
+    const TENS: [&str; 10] = ["", "", "veinte", "treinta", "cuarenta", "cincuenta", "sesenta", "setenta", "ochenta", "noventa"];
+    const HUNDREDS: [&str; 10] = [
+        "", "ciento", "doscientos", "trescientos", "cuatrocientos", "quinientos", "seiscientos", "setecientos",
+        "ochocientos", "novecientos",
+    ];
+
+    if n == 0 {
+        return ONES[0].to_owned();
+    }
+
+    // Hundreds agree with a feminine noun below a million: "doscientas
+    // libras", but "doscientos millones de libras".
+    let below_thousand = |n: u64, feminine: bool| -> String {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let mut words = Vec::new();
+
+        match hundreds {
+            0 => {},
+            1 if rest == 0 => words.push("cien".to_owned()),
+            h if feminine && h > 1 => words.push(HUNDREDS[h as usize].replace("ientos", "ientas")),
+            h => words.push(HUNDREDS[h as usize].to_owned()),
+        }
+
+        match rest {
+            0 => {},
+            1..=29 => words.push(ONES[rest as usize].to_owned()),
+            _ if rest % 10 == 0 => words.push(TENS[(rest / 10) as usize].to_owned()),
+            _ => words.push(format!("{} y {}", TENS[(rest / 10) as usize], ONES[(rest % 10) as usize])),
+        }
+
+        words.join(" ")
+    };
+
+    let below_million = |n: u64, feminine: bool| -> String {
+        match (n / 1000, n % 1000) {
+            (0, rest) => below_thousand(rest, feminine),
+            (1, 0) => "mil".to_owned(),
+            (1, rest) => format!("mil {}", below_thousand(rest, feminine)),
+            (thousands, 0) => format!("{} mil", below_thousand(thousands, feminine)),
+            (thousands, rest) => format!("{} mil {}", below_thousand(thousands, feminine), below_thousand(rest, feminine)),
+        }
+    };
+
+    // Spanish uses the long scale, so numbers are split into groups of six
+    // digits: a billón is a million millones.
+    const SCALES: [(&str, &str); 4] = [("", ""), ("millón", "millones"), ("billón", "billones"), ("trillón", "trillones")];
+    let mut words = Vec::new();
+    let mut rest = n;
+    let mut chunks = Vec::new();
+
+    while rest > 0 {
+        chunks.push(rest % 1_000_000);
+        rest /= 1_000_000;
+    }
+
+    for (scale, &chunk) in chunks.iter().enumerate().rev().filter(|&(_, &chunk)| chunk > 0) {
+        words.push(match scale {
+            0 => below_million(chunk, feminine),
+            _ if chunk == 1 => format!("un {}", SCALES[scale].0),
+            _ => format!("{} {}", below_million(chunk, false), SCALES[scale].1),
+        });
+    }
+
+    let words = words.join(" ");
+
+    if !feminine {
+        words
+    } else if words.ends_with("veintiún") {
+        words.trim_end_matches("veintiún").to_owned() + "veintiuna"
+    } else if words == "un" || words.ends_with(" un") {
+        words + "a"
+    } else {
+        words
+    }
+}
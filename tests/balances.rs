@@ -0,0 +1,78 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::{Balances, Money, alpha3};
+
+#[test]
+fn keeps_currencies_apart() {
+    let usd = alpha3("USD").unwrap();
+    let eur = alpha3("EUR").unwrap();
+
+    let mut balances = Balances::new();
+    balances += Money::new(150, usd);
+    balances += Money::new(200, eur);
+    balances -= Money::new(50, usd);
+
+    assert_eq!(balances.len(), 2);
+    assert_eq!(balances.get(usd).unwrap().amount, 100);
+    assert_eq!(balances.get(eur).unwrap().amount, 200);
+    assert!(balances.get(alpha3("JPY").unwrap()).is_none());
+}
+
+#[test]
+fn iterates_in_code_order_and_prunes_zero() {
+    let balances: Balances = vec![
+        Money::new(1, alpha3("USD").unwrap()),
+        Money::new(2, alpha3("CHF").unwrap()),
+        Money::new(3, alpha3("EUR").unwrap()),
+        Money::new(-1, alpha3("USD").unwrap()),
+    ].into_iter().sum();
+
+    let codes = balances.iter().map(|m| m.currency.alpha3).collect::<Vec<_>>();
+    assert_eq!(codes, ["CHF", "EUR", "USD"]);
+
+    let mut balances = balances;
+    balances.prune_zero();
+    let codes = balances.iter().map(|m| m.currency.alpha3).collect::<Vec<_>>();
+    assert_eq!(codes, ["CHF", "EUR"]);
+}
+
+#[test]
+fn subtracts_other_balances() {
+    let usd = alpha3("USD").unwrap();
+    let gbp = alpha3("GBP").unwrap();
+
+    let mut assets: Balances = vec![Money::new(500, usd)].into_iter().collect();
+    let liabilities: Balances = vec![Money::new(200, usd), Money::new(300, gbp)].into_iter().collect();
+    assets -= &liabilities;
+
+    assert_eq!(assets.get(usd).unwrap().amount, 300);
+    assert_eq!(assets.get(gbp).unwrap().amount, -300);
+}
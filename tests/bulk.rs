@@ -0,0 +1,67 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::{Currency, ParseCurrencyError, RowError, all, parse_many, validate_bytes};
+
+#[test]
+fn parses_many() {
+    let result = parse_many(&["EUR", "usd", "JPY", "", "EURO", "DEM"]);
+
+    assert_eq!(result.currencies().take(3).collect::<Vec<_>>(), [Some(Currency::EUR), None, Some(Currency::JPY)]);
+    assert_eq!(result.errors().iter().map(|e| e.row).collect::<Vec<_>>(), [1, 3, 4, 5]);
+    assert_eq!(result.errors()[1], RowError { row: 3, error: ParseCurrencyError::Empty });
+    assert_eq!(result.errors()[2].error, ParseCurrencyError::InvalidLength(4));
+    assert_eq!((result.len(), result.currency(6)), (6, None));
+    assert!(!result.is_valid());
+}
+
+#[test]
+fn validates_bytes() {
+    let result = validate_bytes(&[*b"GBP", *b"G1P", [b'E', 0xff, b'R'], *b"ZZZ", *b"\0\0\0", [0xc3, 0xa9, 0xff]]);
+
+    assert_eq!(result.currency(0), Some(Currency::GBP));
+    assert_eq!(result.errors()[0].error, ParseCurrencyError::InvalidCharacter { position: 1, found: '1' });
+    assert_eq!(result.errors()[1].error, ParseCurrencyError::InvalidCharacter { position: 1, found: '\u{FFFD}' });
+    assert_eq!(result.errors()[2].error, ParseCurrencyError::Unknown { suggestion: None });
+    assert_eq!(result.errors()[4].error, ParseCurrencyError::InvalidCharacter { position: 1, found: '\u{FFFD}' });
+    assert_eq!(result.errors().len(), 5);
+}
+
+#[test]
+fn validates_every_code() {
+    let codes = all().iter().map(|c| c.alpha3).collect::<Vec<_>>();
+    let bytes = codes.iter().map(|c| [c.as_bytes()[0], c.as_bytes()[1], c.as_bytes()[2]]).collect::<Vec<_>>();
+
+    let result = parse_many(&codes);
+
+    assert!(result.is_valid());
+    assert!(result.currencies().eq(all().iter().map(Currency::from_code)));
+    assert_eq!(validate_bytes(&bytes), result);
+}
@@ -0,0 +1,76 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+extern crate iso4217;
+
+use iso4217::{Currency, CurrencyMap, CurrencySet};
+
+const EUROPE: CurrencySet = CurrencySet::from_slice(&[Currency::EUR, Currency::GBP, Currency::CHF]);
+const MAJORS: CurrencySet = CurrencySet::new().with(Currency::USD).with(Currency::EUR).with(Currency::JPY);
+
+#[test]
+fn set_operations() {
+    assert_eq!((EUROPE | MAJORS).len(), 5);
+    assert_eq!((EUROPE & MAJORS).iter().collect::<Vec<_>>(), [Currency::EUR]);
+    assert_eq!((EUROPE - MAJORS).iter().collect::<Vec<_>>(), [Currency::CHF, Currency::GBP]);
+    assert!(CurrencySet::from_slice(&[Currency::EUR]).is_subset(&EUROPE));
+    assert_eq!(CurrencySet::full().len(), Currency::COUNT);
+    assert!(CurrencySet::new().is_empty());
+}
+
+#[test]
+fn set_insert_remove_iter() {
+    let mut set = CurrencySet::new();
+
+    assert!(set.insert(Currency::ZMW));
+    assert!(!set.insert(Currency::ZMW));
+    set.extend(vec![Currency::ALL, Currency::BRL]);
+
+    let order = set.iter().map(|c| c.index()).collect::<Vec<_>>();
+
+    assert!(order.windows(2).all(|w| w[0] < w[1]));
+    assert!(set.remove(Currency::ZMW));
+    assert!(!set.contains(Currency::ZMW));
+    assert_eq!(CurrencySet::full().iter().count(), Currency::COUNT);
+}
+
+#[test]
+fn map_operations() {
+    const LIMITS: CurrencyMap<u32> = CurrencyMap::new().with(Currency::EUR, 100).with(Currency::USD, 120);
+
+    let mut names: CurrencyMap<String> = CurrencyMap::new();
+
+    assert_eq!(names.insert(Currency::EUR, "euro".to_owned()), None);
+    assert_eq!(names.insert(Currency::EUR, "Euro".to_owned()), Some("euro".to_owned()));
+    names.get_mut(Currency::EUR).unwrap().push('!');
+
+    assert_eq!(names[Currency::EUR], "Euro!");
+    assert_eq!(LIMITS.len(), 2);
+    assert_eq!(LIMITS.keys(), CurrencySet::from_slice(&[Currency::USD, Currency::EUR]));
+    assert_eq!(LIMITS.iter().map(|(c, v)| (c, *v)).collect::<Vec<_>>(), [(Currency::USD, 120), (Currency::EUR, 100)]);
+    assert_eq!(names.remove(Currency::EUR), Some("Euro!".to_owned()));
+    assert!(names.is_empty());
+}
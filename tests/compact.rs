@@ -0,0 +1,77 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+extern crate iso4217;
+
+use std::convert::TryFrom;
+use std::mem;
+
+use iso4217::{Currency, CurrencyCode, all, alpha3};
+
+#[test]
+fn currency_size() {
+    assert_eq!(mem::size_of::<Currency>(), 2);
+    assert_eq!(mem::size_of::<Option<Currency>>(), 2);
+    assert_eq!(Currency::EUR as u16, 978);
+}
+
+#[test]
+fn currency_round_trips() {
+    assert_eq!(all().len(), Currency::COUNT);
+
+    for (index, code) in all().iter().enumerate() {
+        let currency = Currency::from_code(code).unwrap();
+
+        assert_eq!(currency.index() as usize, index);
+        assert_eq!(Currency::from_index(index as u8), Some(currency));
+        assert_eq!(Currency::from_num(currency.num()), Some(currency));
+        assert_eq!(currency.code(), code);
+        assert_eq!(code.num, format!("{:03}", currency as u16));
+    }
+
+    assert_eq!(Currency::from_index(Currency::COUNT as u8), None);
+
+    let mut codes = all().iter().collect::<Vec<_>>();
+    let mut currencies = all().iter().map(|code| Currency::from_code(code).unwrap()).collect::<Vec<_>>();
+    codes.sort();
+    currencies.sort();
+
+    assert!(currencies.iter().map(|currency| currency.code()).eq(codes));
+    assert!(Currency::EUR < Currency::USD);
+}
+
+#[test]
+fn currency_conversions() {
+    const BASE: &CurrencyCode = Currency::JPY.code();
+
+    assert_eq!(BASE.alpha3, "JPY");
+    assert_eq!("USD".parse::<Currency>(), Ok(Currency::USD));
+    assert_eq!(Currency::try_from(826), Ok(Currency::GBP));
+    assert_eq!(Currency::try_from(alpha3("CHF").unwrap()), Ok(Currency::CHF));
+    assert!("DEM".parse::<Currency>().is_err());
+    assert_eq!(<&CurrencyCode>::from(Currency::EUR).name, "Euro");
+    assert_eq!(format!("{} {:#}", Currency::EUR, Currency::EUR), "EUR Euro");
+}
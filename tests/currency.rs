@@ -0,0 +1,103 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+extern crate iso4217;
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use iso4217::{CurrencyCode, ParseCurrencyError, all, alpha3, withdrawn};
+
+#[test]
+fn eq_hash_ord() {
+    let eur = alpha3("EUR").unwrap();
+
+    assert_eq!(eur, &"EUR".parse::<CurrencyCode>().unwrap());
+    assert_ne!(eur, alpha3("USD").unwrap());
+    assert!(eur < alpha3("USD").unwrap());
+    assert_eq!(all().iter().collect::<HashSet<_>>().len(), all().len());
+
+    let mut sorted = all().to_vec();
+    sorted.sort();
+
+    assert_eq!(sorted[0].alpha3, "AED");
+}
+
+#[test]
+fn display() {
+    let eur = alpha3("EUR").unwrap();
+
+    assert_eq!(format!("{}", eur), "EUR");
+    assert_eq!(format!("{:#}", eur), "Euro");
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(<&CurrencyCode>::try_from(978).unwrap().alpha3, "EUR");
+    assert_eq!(<&CurrencyCode>::try_from("JPY").unwrap().num, "392");
+    assert!(<&CurrencyCode>::try_from(979).is_err());
+    assert!("eur".parse::<CurrencyCode>().is_err());
+}
+
+#[test]
+fn parse_errors() {
+    let eur = alpha3("EUR").unwrap();
+    let parse = |code: &str| code.parse::<CurrencyCode>().unwrap_err();
+
+    assert_eq!(parse(""), ParseCurrencyError::Empty);
+    assert_eq!(parse("EURO"), ParseCurrencyError::InvalidLength(4));
+    assert_eq!(parse("E1R"), ParseCurrencyError::InvalidCharacter { position: 1, found: '1' });
+    assert_eq!(parse("EUD"), ParseCurrencyError::Unknown { suggestion: Some(eur) });
+    assert_eq!(parse("QQQ"), ParseCurrencyError::Unknown { suggestion: None });
+    let dem = withdrawn().find(|c| c.alpha3 == "DEM").unwrap();
+
+    assert_eq!(parse("DEM"), ParseCurrencyError::Withdrawn { currency: dem, replaced_by: Some(eur) });
+    assert_eq!(<&CurrencyCode>::try_from(276).unwrap_err(), parse("DEM"));
+    assert_eq!(<&CurrencyCode>::try_from(798).unwrap_err(), ParseCurrencyError::Unknown { suggestion: Some(eur) });
+}
+
+#[test]
+fn parse_error_messages() {
+    let message = |code: &str| code.parse::<CurrencyCode>().unwrap_err().to_string();
+
+    assert_eq!(message("eur"), "unknown currency code, did you mean EUR (Euro)?");
+    assert_eq!(message("FRF"), "FRF (French franc) has been withdrawn, replaced by EUR (Euro)");
+    assert_eq!(message("XEU"), "XEU (European Currency Unit) has been withdrawn, replaced by EUR (Euro)");
+    assert_eq!(message("US"), "currency codes have 3 characters, found 2");
+}
+
+#[test]
+fn withdrawn_codes() {
+    for currency in withdrawn() {
+        assert!(alpha3(currency.alpha3).is_none());
+        assert!(all().iter().all(|c| c.num != currency.num), "{} is in use", currency.num);
+
+        match currency.alpha3.parse::<CurrencyCode>() {
+            Err(ParseCurrencyError::Withdrawn { replaced_by: Some(_), .. }) => {},
+            other => panic!("{} parsed as {:?}", currency.alpha3, other),
+        }
+    }
+}
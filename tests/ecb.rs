@@ -0,0 +1,85 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "ecb")]
+
+extern crate iso4217;
+
+use iso4217::ecb::{self, EcbError, Parser};
+use iso4217::{Date, RateStore, RoundingMode, Money, alpha3};
+
+const DAILY: &str = "tests/fixtures/eurofxref-daily.xml";
+const HIST: &str = "tests/fixtures/eurofxref-hist.csv";
+const UNKNOWN: &str = "tests/fixtures/eurofxref-unknown.xml";
+
+#[test]
+fn load_daily_xml() {
+    let rates = ecb::load_xml(DAILY).unwrap();
+    let date = "2024-03-15".parse::<Date>().unwrap();
+
+    assert_eq!(rates.len(), 4);
+    assert!(rates.iter().all(|&(d, ref rate)| d == date && rate.base.alpha3 == "EUR"));
+    assert_eq!(rates[1].1.quote.alpha3, "JPY");
+    assert_eq!(rates[1].1.rate, "161.98".parse().unwrap());
+}
+
+#[test]
+fn load_historical_csv() {
+    let err = ecb::load_csv(HIST).unwrap_err();
+    match err {
+        EcbError::UnknownCurrency { line, ref code } => assert_eq!((line, code.as_str()), (4, "CYP")),
+        other => panic!("unexpected error: {}", other),
+    }
+
+    let rates = Parser::new().ignore_unknown(true).load_csv(HIST).unwrap();
+    assert_eq!(rates.len(), 9);
+
+    let mut store = RateStore::new();
+    store.extend(rates);
+
+    let eur = alpha3("EUR").unwrap();
+    let usd = alpha3("USD").unwrap();
+    let date = "2024-03-14".parse().unwrap();
+    let converted = store.convert(Money::new(10_000, eur), usd, date, RoundingMode::HalfUp).unwrap();
+    assert_eq!(converted.amount, 10_925);
+}
+
+#[test]
+fn unknown_currency_in_xml() {
+    match ecb::load_xml(UNKNOWN).unwrap_err() {
+        EcbError::UnknownCurrency { line, ref code } => assert_eq!((line, code.as_str()), (6, "XYZ")),
+        other => panic!("unexpected error: {}", other),
+    }
+
+    assert_eq!(Parser::new().ignore_unknown(true).load_xml(UNKNOWN).unwrap().len(), 1);
+}
+
+#[test]
+fn malformed_input() {
+    assert!(Parser::new().parse_csv("Currency,USD\n").is_err());
+    assert!(Parser::new().parse_xml("<Cube><Cube currency='USD' rate='1.1'/></Cube>").is_err());
+    assert!(ecb::load_xml("tests/fixtures/missing.xml").is_err());
+}
@@ -0,0 +1,88 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+extern crate iso4217;
+
+use iso4217::{ExchangeRate, Money, ParseRateError, Rate, RoundingMode, alpha3, convert};
+
+fn rate(base: &str, quote: &str, rate: &str) -> ExchangeRate {
+    ExchangeRate::new(alpha3(base).unwrap(), alpha3(quote).unwrap(), rate.parse().unwrap())
+}
+
+#[test]
+fn parse_rate() {
+    let rate: Rate = "1.0856".parse().unwrap();
+    assert_eq!((rate.numerator(), rate.denominator()), (1357, 1250));
+
+    assert_eq!("0".parse::<Rate>(), Err(ParseRateError::Zero));
+    assert_eq!("1,2".parse::<Rate>(), Err(ParseRateError::Invalid));
+    assert_eq!("-1".parse::<Rate>(), Err(ParseRateError::Invalid));
+    assert_eq!(".".parse::<Rate>(), Err(ParseRateError::Invalid));
+}
+
+#[test]
+fn convert_between_exponents() {
+    let eur_usd = rate("EUR", "USD", "1.0856");
+    let eur_jpy = rate("EUR", "JPY", "161.5");
+    let eur = alpha3("EUR").unwrap();
+
+    let usd = convert(Money::new(10_000, eur), &eur_usd, RoundingMode::HalfEven).unwrap();
+    assert_eq!((usd.amount, usd.currency.alpha3), (10_856, "USD"));
+
+    let jpy = convert(Money::new(1_003, eur), &eur_jpy, RoundingMode::HalfUp).unwrap();
+    assert_eq!((jpy.amount, jpy.currency.alpha3), (1_620, "JPY"));
+
+    let back = eur_jpy.convert(Money::new(1_620, alpha3("JPY").unwrap()), RoundingMode::HalfUp).unwrap();
+    assert_eq!((back.amount, back.currency.alpha3), (1_003, "EUR"));
+
+    assert!(convert(Money::new(1, alpha3("GBP").unwrap()), &eur_usd, RoundingMode::HalfUp).is_none());
+}
+
+#[test]
+fn inverse_and_cross_rates() {
+    let eur_usd = rate("EUR", "USD", "1.25");
+    let usd_jpy = rate("USD", "JPY", "150");
+
+    let usd_eur = eur_usd.inverse();
+    assert_eq!(usd_eur.base.alpha3, "USD");
+    assert_eq!(usd_eur.rate, Rate::new(4, 5).unwrap());
+
+    let eur_jpy = eur_usd.cross(&usd_jpy).unwrap();
+    assert_eq!((eur_jpy.base.alpha3, eur_jpy.quote.alpha3), ("EUR", "JPY"));
+    assert_eq!(eur_jpy.rate, Rate::new(375, 2).unwrap());
+
+    let gbp_usd = rate("GBP", "USD", "1.5");
+    let eur_gbp = eur_usd.cross(&gbp_usd.inverse()).unwrap();
+    assert_eq!((eur_gbp.base.alpha3, eur_gbp.quote.alpha3), ("EUR", "GBP"));
+    assert_eq!(eur_gbp.rate, Rate::new(5, 6).unwrap());
+
+    // Both quoted against the pivot.
+    let eur_gbp = eur_usd.cross(&gbp_usd).unwrap();
+    assert_eq!((eur_gbp.base.alpha3, eur_gbp.quote.alpha3), ("EUR", "GBP"));
+    assert_eq!(eur_gbp.rate, Rate::new(5, 6).unwrap());
+    assert!(eur_usd.cross(&usd_eur).is_none());
+    assert!(eur_usd.cross(&rate("GBP", "CHF", "1.1")).is_none());
+}
@@ -0,0 +1,161 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::{CurrencyDisplay, Locale, Money, MoneyFormatter, Notation, NumberingSystem, RoundingMode, alpha3};
+
+fn format(tag: &str, amount: i64, code: &str) -> String {
+    MoneyFormatter::new(Locale::get(tag).unwrap()).format(amount, alpha3(code).unwrap())
+}
+
+#[test]
+fn symbol_placement_and_separators() {
+    assert_eq!(format("de-DE", 123_456, "EUR"), "1.234,56\u{a0}€");
+    assert_eq!(format("en-IE", 123_456, "EUR"), "€1,234.56");
+    assert_eq!(format("de-CH", 123_456, "CHF"), "CHF\u{a0}1’234.56");
+    assert_eq!(format("en-US", 120_000, "JPY"), "¥120,000");
+    assert_eq!(format("en-GB", 5, "USD"), "US$0.05");
+    assert_eq!(format("en-US", 1_234_567, "KWD"), "KWD\u{a0}1,234.567");
+}
+
+#[test]
+fn negative_amounts() {
+    let en = Locale::get("en-US").unwrap();
+    let usd = alpha3("USD").unwrap();
+
+    assert_eq!(format("en-US", -123_456, "USD"), "-$1,234.56");
+    assert_eq!(format("de-CH", -123_456, "CHF"), "CHF-1’234.56");
+    assert_eq!(format("sv", -100, "SEK"), "\u{2212}1,00\u{a0}kr");
+    assert_eq!(MoneyFormatter::new(en).accounting(true).format(-123_456, usd), "($1,234.56)");
+    assert_eq!(MoneyFormatter::new(en).accounting(true).format(123_456, usd), "$1,234.56");
+}
+
+#[test]
+fn code_and_name_display() {
+    let en = MoneyFormatter::new(Locale::get("en").unwrap());
+    let de = MoneyFormatter::new(Locale::get("de_de").unwrap());
+    let usd = Money::new(123_456, alpha3("USD").unwrap());
+
+    assert_eq!(en.display(CurrencyDisplay::Code).format_money(usd), "USD\u{a0}1,234.56");
+    assert_eq!(de.display(CurrencyDisplay::Code).format_money(usd), "1.234,56\u{a0}USD");
+    assert_eq!(en.display(CurrencyDisplay::Name).format_money(usd), "1,234.56 United States dollar");
+    assert_eq!(de.display(CurrencyDisplay::Name).accounting(true).format(-5, usd.currency), "-0,05 United States dollar");
+}
+
+#[test]
+fn minimum_grouping_and_locale_fallback() {
+    assert_eq!(format("es", 123_400, "EUR"), "1234,00\u{a0}€");
+    assert_eq!(format("es", 1_234_500, "EUR"), "12.345,00\u{a0}€");
+    assert_eq!(Locale::get("de-LU").unwrap().tag, "de");
+    assert!(Locale::get("tlh").is_none());
+}
+
+#[test]
+fn indian_grouping() {
+    assert_eq!(format("en-IN", 123_456_789, "INR"), "₹12,34,567.89");
+    assert_eq!(format("en-IN", 99_999, "INR"), "₹999.99");
+    assert_eq!(format("hi", 1_000_000_000, "INR"), "₹1,00,00,000.00");
+    assert_eq!(format("bn", 12_345_600, "BDT"), "১,২৩,৪৫৬.০০৳");
+}
+
+#[test]
+fn numbering_systems() {
+    let inr = alpha3("INR").unwrap();
+    let devanagari = MoneyFormatter::from_tag("hi-IN-u-nu-deva").unwrap();
+
+    assert_eq!(devanagari.format(123_456_789, inr), "₹१२,३४,५६७.८९");
+    assert_eq!(format("mr", 150, "INR"), "₹१.५०");
+    assert_eq!(format("ar-EG", -123_456, "EGP"), "\u{61c}-\u{200f}١٬٢٣٤٫٥٦\u{a0}ج.م.\u{200f}");
+    assert_eq!(MoneyFormatter::from_tag("ar-EG-u-nu-latn").unwrap().format(100, inr), "\u{200f}1٫00\u{a0}₹");
+    assert_eq!(NumberingSystem::from_tag("fa-u-ca-persian-nu-arabext"), Some(NumberingSystem::ArabExt));
+    assert_eq!(NumberingSystem::from_tag("en-US"), None);
+}
+
+#[test]
+fn formats_every_currency() {
+    let formatter = MoneyFormatter::new(Locale::get("en-IN").unwrap());
+
+    for currency in iso4217::all() {
+        let formatted = formatter.format(-123_456_789, currency);
+
+        assert!(formatted.starts_with('-'), "{}", formatted);
+        assert_eq!(formatted.chars().filter(char::is_ascii_digit).count(), 9, "{}", formatted);
+        assert_eq!(formatted.contains('.'), currency.exp > 0, "{}", formatted);
+    }
+}
+
+#[test]
+fn compact_notation() {
+    let compact = |tag: &str, notation: Notation, amount: i64, code: &str| {
+        MoneyFormatter::new(Locale::get(tag).unwrap()).notation(notation).format(amount, alpha3(code).unwrap())
+    };
+
+    assert_eq!(compact("en", Notation::CompactShort, 123_456_789, "USD"), "$1.2M");
+    assert_eq!(compact("en", Notation::CompactShort, 340_000, "EUR"), "€3.4K");
+    assert_eq!(compact("en", Notation::CompactShort, -12_345_600, "USD"), "-$123K");
+    assert_eq!(compact("en", Notation::CompactShort, 99_995_000, "USD"), "$1M");
+    assert_eq!(compact("en", Notation::CompactShort, 99_999, "USD"), "$999.99");
+    assert_eq!(compact("de", Notation::CompactShort, 123_456_789, "EUR"), "1,2\u{a0}Mio.\u{a0}€");
+    assert_eq!(compact("de", Notation::CompactShort, 123_456, "EUR"), "1.234,56\u{a0}€");
+    assert_eq!(compact("ja", Notation::CompactShort, 123_456_789, "JPY"), "￥1.2億");
+    assert_eq!(compact("en-IN", Notation::CompactShort, 250_000_000, "INR"), "₹25L");
+}
+
+#[test]
+fn compact_long_notation() {
+    let compact = |tag: &str, amount: i64, code: &str| {
+        MoneyFormatter::new(Locale::get(tag).unwrap()).notation(Notation::CompactLong).format(amount, alpha3(code).unwrap())
+    };
+
+    assert_eq!(compact("en", 123_456_789, "USD"), "$1.2 million");
+    assert_eq!(compact("de", 123_456_789, "EUR"), "1,2 Millionen\u{a0}€");
+    assert_eq!(compact("de", 100_000_000, "EUR"), "1 Million\u{a0}€");
+    assert_eq!(compact("fr", 123_456_789, "EUR"), "1,2 million\u{a0}€");
+    assert_eq!(compact("fr", 234_567_890, "EUR"), "2,3 millions\u{a0}€");
+    assert_eq!(compact("pt", 123_456_789, "BRL"), "R$\u{a0}1,2 milhão");
+
+    let deva = MoneyFormatter::new(Locale::get("de").unwrap()).numbering(NumberingSystem::Deva).notation(Notation::CompactLong);
+
+    assert_eq!(deva.format(100_000_000, alpha3("EUR").unwrap()), "\u{967} Million\u{a0}€");
+    assert_eq!(compact("pl", 123_456_789, "PLN"), "1,2\u{a0}mln\u{a0}zł");
+}
+
+#[test]
+fn compact_significant_digits_and_rounding() {
+    let en = MoneyFormatter::new(Locale::get("en").unwrap()).notation(Notation::CompactShort);
+    let usd = alpha3("USD").unwrap();
+
+    assert_eq!(en.significant_digits(4).format(123_456_789, usd), "$1.235M");
+    assert_eq!(en.significant_digits(1).format(123_456_789, usd), "$1M");
+    assert_eq!(en.significant_digits(40).format(123_456_789, usd), "$1.23456789M");
+    assert_eq!(en.significant_digits(25).format(i64::MAX, usd), "$92233.7203685477581T");
+    assert_eq!(en.rounding(RoundingMode::Up).format(110_000_001, usd), "$1.2M");
+    assert_eq!(en.rounding(RoundingMode::HalfEven).format(125_000_000, usd), "$1.2M");
+    assert_eq!(en.display(CurrencyDisplay::Code).format(125_000_000, usd), "USD\u{a0}1.2M");
+}
@@ -28,7 +28,9 @@ extern crate iso4217;
 
 use std::collections::HashSet;
 
-use iso4217::{CurrencyCode, all, alpha3, country, exp, name, num};
+use iso4217::{CurrencyCode, all, alpha3, alpha3_const, name, num, num_const};
+#[cfg(feature = "alloc")]
+use iso4217::{alias, aliases, alpha3_normalized, country, country_iter, exp, exp_iter, name_normalized, num_normalized};
 
 #[test]
 fn get_all() {
@@ -41,15 +43,26 @@ fn get_by_alpha3() {
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn get_by_country() {
     assert!(!country("AL").is_empty());
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn get_by_exp() {
     assert!(!exp(2).is_empty());
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn get_by_country_and_exp_iter() {
+    assert!(country_iter("AL").map(|c| c.alpha3).eq(country("AL").iter().map(|c| c.alpha3)));
+    assert!(exp_iter(3).all(|c| c.exp == 3));
+    assert_eq!(exp_iter(0).count(), exp(0).len());
+    assert_eq!(country_iter("ZZ").count(), 0);
+}
+
 #[test]
 fn get_by_name() {
     assert!(name("Albanian lek").is_some());
@@ -60,6 +73,97 @@ fn get_by_num() {
     assert!(num("008").is_some());
 }
 
+#[test]
+fn get_in_const() {
+    const EUR: &CurrencyCode = alpha3_const("EUR");
+    static USD: &CurrencyCode = num_const(840);
+    const ALL_LEK: &CurrencyCode = num_const(8);
+
+    assert_eq!(EUR.num, "978");
+    assert_eq!(USD.alpha3, "USD");
+    assert_eq!(ALL_LEK.alpha3, "ALL");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn get_by_normalized_alpha3() {
+    assert!(alpha3("eur").is_none());
+    assert_eq!(alpha3_normalized(" eur\t").unwrap().alpha3, "EUR");
+    assert_eq!(alpha3_normalized("ＵＳＤ").unwrap().alpha3, "USD");
+    assert!(alpha3_normalized("eu").is_none());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn get_by_normalized_name() {
+    assert_eq!(name_normalized("polish zloty").unwrap().alpha3, "PLN");
+    assert_eq!(name_normalized("  Sa\u{303}o Tome\u{301} and  PRÍNCIPE dobra").unwrap().alpha3, "STD");
+    assert_eq!(name_normalized("tongan pa\u{2019}anga").unwrap().alpha3, "TOP");
+    assert!(name_normalized("us dollar").is_none());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn get_by_alias() {
+    assert_eq!(alias("US Dollar").unwrap().alpha3, "USD");
+    assert_eq!(alias("euro").unwrap().alpha3, "EUR");
+    assert_eq!(alias("Sterling").unwrap().alpha3, "GBP");
+    assert_eq!(alias("Swiss Franc").unwrap().alpha3, "CHF");
+    assert_eq!(alias("Renminbi").unwrap().alpha3, "CNY");
+    assert_eq!(alias("YUAN").unwrap().alpha3, "CNY");
+    assert!(alias("dollar").is_none());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn aliases_are_unambiguous() {
+    let mut seen = HashSet::new();
+
+    for currency in all() {
+        assert!(seen.insert(currency.name.to_lowercase()), "{} is duplicated", currency.name);
+    }
+
+    for currency in all() {
+        for name in aliases(currency.alpha3) {
+            assert!(seen.insert(name.to_lowercase()), "{} is ambiguous", name);
+            assert!(name_normalized(name).is_none(), "{} folds like a name", name);
+            assert!(alpha3_normalized(name).is_none(), "{} reads as an Alpha3 code", name);
+            assert_eq!(alias(name).unwrap().alpha3, currency.alpha3);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn get_by_normalized_num() {
+    assert_eq!(num_normalized(" 8 ").unwrap().alpha3, "ALL");
+    assert_eq!(num_normalized("840").unwrap().alpha3, "USD");
+    assert!(num_normalized("0840").is_none());
+    assert!(num_normalized("").is_none());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn indexes_match_table() {
+    for currency in all() {
+        assert_eq!(alpha3(currency.alpha3).unwrap().alpha3, currency.alpha3);
+        assert_eq!(num(currency.num).unwrap().alpha3, currency.alpha3);
+        assert_eq!(name(currency.name).unwrap().alpha3, currency.alpha3);
+        assert_eq!(name_normalized(&currency.name.to_uppercase()).unwrap().alpha3, currency.alpha3);
+
+        for code in currency.countries {
+            let expected = all().iter().filter(|c| c.countries.contains(code)).map(|c| c.alpha3);
+
+            assert!(country(code).iter().map(|c| c.alpha3).eq(expected));
+        }
+    }
+
+    assert_eq!(country("SH-AC")[0].alpha3, "SHP");
+    assert!(country("ZZ").is_empty());
+    assert!(alpha3("usd").is_none());
+    assert!(num("84").is_none());
+}
+
 #[test]
 fn backwards_compats() {
     // Test the number of total CurrencyCodes.
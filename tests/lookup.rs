@@ -0,0 +1,62 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::{LookupError, MatchKind, lookup};
+
+#[test]
+fn detects_kind() {
+    let cases = [
+        ("840", MatchKind::Numeric),
+        ("usd", MatchKind::Alpha3),
+        ("United States Dollar", MatchKind::Name),
+        ("US dollar", MatchKind::Alias),
+    ];
+
+    for &(input, kind) in &cases {
+        let found = lookup(input).unwrap();
+
+        assert_eq!(found.currency.alpha3, "USD");
+        assert_eq!(found.kind, kind);
+    }
+}
+
+#[test]
+fn normalizes_input() {
+    assert_eq!(lookup(" 8 ").unwrap().currency.alpha3, "ALL");
+    assert_eq!(lookup("RMB").unwrap().kind, MatchKind::Alias);
+    assert_eq!(lookup("polish złoty").unwrap().currency.alpha3, "PLN");
+}
+
+#[test]
+fn reports_errors() {
+    assert_eq!(lookup("  ").unwrap_err(), LookupError::Empty);
+    assert_eq!(lookup("dollar").unwrap_err(), LookupError::NotFound("dollar".to_owned()));
+    assert_eq!(lookup("999").unwrap_err().to_string(), "unknown currency \"999\"");
+}
@@ -0,0 +1,54 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#[macro_use]
+extern crate iso4217;
+
+use iso4217::CurrencyCode;
+
+static BASES: [&CurrencyCode; 3] = [currency!("EUR"), currency!("USD"), currency!(826)];
+
+#[test]
+fn resolves_alpha3() {
+    assert_eq!(currency!("JPY").num, "392");
+    assert_eq!(BASES[0].name, "Euro");
+}
+
+#[test]
+fn resolves_numeric() {
+    assert_eq!(currency!(978).alpha3, "EUR");
+    assert_eq!(currency!(8).alpha3, "ALL");
+    assert_eq!(BASES[2].alpha3, "GBP");
+}
+
+#[test]
+fn suggests_nearest_code() {
+    let message = |literal| iso4217::macros::resolve(literal).unwrap_err();
+
+    assert_eq!(message("\"EUD\"").as_str(), "unknown currency code \"EUD\", did you mean \"EUR\"?");
+    assert_eq!(message("979").as_str(), "unknown currency code 979, did you mean 978?");
+    assert_eq!(message("798").as_str(), "unknown currency code 798, did you mean 978?");
+}
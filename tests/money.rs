@@ -0,0 +1,79 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+extern crate iso4217;
+
+use iso4217::{Money, RoundingMode, alpha3};
+
+#[test]
+fn mul_ratio_rounds_to_minor_unit() {
+    let usd = alpha3("USD").unwrap();
+    let price = Money::new(1999, usd);
+
+    // 19.99 * 0.0725 = 1.449275
+    let tax = price.mul_ratio(725, 10_000, RoundingMode::HalfUp).unwrap();
+    assert_eq!(tax.amount, 145);
+    assert_eq!(tax.currency.alpha3, "USD");
+
+    let tax = price.mul_ratio(725, 10_000, RoundingMode::Down).unwrap();
+    assert_eq!(tax.amount, 144);
+}
+
+#[test]
+fn mul_ratio_rejects_zero_denominator_and_overflow() {
+    let usd = alpha3("USD").unwrap();
+
+    assert!(Money::new(100, usd).mul_ratio(1, 0, RoundingMode::HalfUp).is_none());
+    assert!(Money::new(i64::MAX, usd).mul_ratio(2, 1, RoundingMode::HalfUp).is_none());
+}
+
+#[test]
+fn rounding_modes_on_ties() {
+    let eur = alpha3("EUR").unwrap();
+    let modes = [
+        (RoundingMode::Down, 2, -2),
+        (RoundingMode::Up, 3, -3),
+        (RoundingMode::Floor, 2, -3),
+        (RoundingMode::Ceiling, 3, -2),
+        (RoundingMode::HalfUp, 3, -3),
+        (RoundingMode::HalfDown, 2, -2),
+        (RoundingMode::HalfEven, 2, -2),
+    ];
+
+    for &(mode, positive, negative) in &modes {
+        assert_eq!(Money::new(5, eur).mul_ratio(1, 2, mode).unwrap().amount, positive, "{:?}", mode);
+        assert_eq!(Money::new(-5, eur).mul_ratio(1, 2, mode).unwrap().amount, negative, "{:?}", mode);
+    }
+}
+
+#[test]
+fn percent_and_basis_points() {
+    let jpy = alpha3("JPY").unwrap();
+    let bhd = alpha3("BHD").unwrap();
+
+    assert_eq!(Money::new(1000, jpy).mul_percent(725, 2, RoundingMode::HalfEven).unwrap().amount, 72);
+    assert_eq!(Money::new(10_000, bhd).mul_basis_points(15, RoundingMode::HalfUp).unwrap().amount, 15);
+}
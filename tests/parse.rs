@@ -0,0 +1,75 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::{Locale, Money, ParseMoneyError, parse_money};
+
+fn parse(input: &str, tag: Option<&str>) -> Result<(i64, &'static str), ParseMoneyError> {
+    parse_money(input, tag.map(|tag| Locale::get(tag).unwrap())).map(|m| (m.amount, m.currency.alpha3))
+}
+
+#[test]
+fn codes_symbols_and_names() {
+    assert_eq!(parse("1.234,56 €", None), Ok((123_456, "EUR")));
+    assert_eq!(parse("USD 1,234.56", None), Ok((123_456, "USD")));
+    assert_eq!(parse("-£3.50", None), Ok((-350, "GBP")));
+    assert_eq!(parse("(chf 1’234.50)", None), Ok((-123_450, "CHF")));
+    assert_eq!(parse("12.5 Japanese yen", None), Err(ParseMoneyError::TooManyDecimals { exp: 0, found: 1 }));
+    assert_eq!(parse("3 euros", None), Ok((300, "EUR")));
+    assert_eq!("CHF-1’234.56".parse::<Money>().map(|m| m.amount), Ok(-123_456));
+}
+
+#[test]
+fn locale_hints() {
+    assert_eq!(parse("¥1,200", None), Err(ParseMoneyError::AmbiguousCurrency(vec!["CNY", "JPY"])));
+    assert_eq!(parse("¥1,200", Some("ja")), Ok((1_200, "JPY")));
+    assert_eq!(parse("$5", Some("en-CA")), Ok((500, "CAD")));
+    assert_eq!(parse("1.234,56 €", Some("en")), Err(ParseMoneyError::InvalidNumber));
+    assert_eq!(parse("1\u{202f}234,56\u{a0}€", Some("fr")), Ok((123_456, "EUR")));
+    assert_eq!(parse("\u{61c}-\u{200f}١٬٢٣٤٫٥٦\u{a0}EGP", Some("ar-EG")), Ok((-123_456, "EGP")));
+}
+
+#[test]
+fn separator_ambiguity() {
+    assert_eq!(parse("USD 1,234", None), Ok((123_400, "USD")));
+    assert_eq!(parse("EUR 0,5", None), Ok((50, "EUR")));
+    assert_eq!(parse("KWD 1,234", None), Err(ParseMoneyError::AmbiguousSeparator));
+    assert_eq!(parse("KWD 1,234", Some("de")), Ok((1_234, "KWD")));
+    assert_eq!(parse("INR 12,34,567.89", None), Ok((123_456_789, "INR")));
+    assert_eq!(parse("USD 12,34", Some("en")), Err(ParseMoneyError::InvalidNumber));
+    assert_eq!(parse("USD 1.00.0", None), Err(ParseMoneyError::InvalidNumber));
+}
+
+#[test]
+fn missing_or_unknown_parts() {
+    assert_eq!(parse("", None), Err(ParseMoneyError::Empty));
+    assert_eq!(parse("12.00", None), Err(ParseMoneyError::MissingCurrency));
+    assert_eq!(parse("XYZ 12.00", None), Err(ParseMoneyError::UnknownCurrency("XYZ".to_owned())));
+    assert_eq!(parse("USD 99999999999999999999", None), Err(ParseMoneyError::Overflow));
+}
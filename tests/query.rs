@@ -0,0 +1,66 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+extern crate iso4217;
+
+use iso4217::{Kind, Query, Region, SortBy, Status, all};
+
+#[test]
+fn combined_filters() {
+    let found = Query::new().kind(Kind::National).exp(3).min_countries(2).iter().count();
+    let expected = all().iter().filter(|c| c.exp == 3 && c.countries.len() >= 2 && !c.alpha3.starts_with('X')).count();
+
+    assert_eq!(found, expected);
+    assert_eq!(Query::new().iter().count(), all().len());
+    assert!(Query::new().country("FR").into_iter().all(|c| c.countries.contains(&"FR")));
+}
+
+#[test]
+fn region_and_name() {
+    let codes = Query::new().region(Region::Europe).name_contains("KRON").sort_by(SortBy::Alpha3);
+    let codes = codes.into_iter().map(|c| c.alpha3).collect::<Vec<_>>();
+
+    assert_eq!(codes, ["DKK", "NOK", "SEK"]);
+    assert!(Query::new().region(Region::Oceania).into_iter().any(|c| c.alpha3 == "NZD"));
+    assert!(Query::new().kind(Kind::Supranational).into_iter().any(|c| c.alpha3 == "EUR"));
+}
+
+#[test]
+fn sort_and_limit() {
+    let names = Query::new().sort_by(SortBy::Name).limit(3).into_iter().map(|c| c.name).collect::<Vec<_>>();
+
+    assert_eq!(names, ["Afghan afghani", "Albanian lek", "Algerian dinar"]);
+    assert!(Query::new().into_iter().zip(Query::new().into_iter().skip(1)).all(|(a, b)| a.num < b.num));
+    assert_eq!(Query::new().limit(0).into_iter().count(), 0);
+}
+
+#[test]
+fn status() {
+    let withdrawn = Query::new().status(Status::Withdrawn).country("DE").into_iter().map(|c| c.alpha3).collect::<Vec<_>>();
+
+    assert_eq!(withdrawn, ["DEM"]);
+    assert_eq!(Query::new().status(Status::Withdrawn).exp(0).sort_by(SortBy::Alpha3).into_iter().next().unwrap().alpha3, "ADP");
+}
@@ -0,0 +1,62 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::scan;
+
+fn found(text: &str) -> Vec<(&str, i64, &'static str)> {
+    scan(text).into_iter().map(|m| (&text[m.span], m.money.amount, m.money.currency.alpha3)).collect()
+}
+
+#[test]
+fn finds_codes_symbols_and_names() {
+    assert_eq!(found("Total due: EUR 1 204,00 incl. VAT"), [("EUR 1 204,00", 120_400, "EUR")]);
+    assert_eq!(
+        found("Paid €12.50 and 3,000 JPY, refund -£3.50 (about 4 euros)."),
+        [("€12.50", 1_250, "EUR"), ("3,000 JPY", 3_000, "JPY"), ("-£3.50", -350, "GBP"), ("4 euros", 400, "EUR")]
+    );
+    assert_eq!(found("1,234.56 United States dollars"), [("1,234.56 United States dollars", 123_456, "USD")]);
+}
+
+#[test]
+fn ignores_bare_numbers() {
+    assert!(found("Invoice 2024-117, 3 items, due in 30 days").is_empty());
+    assert!(found("").is_empty());
+}
+
+#[test]
+fn confidence_reflects_ambiguity() {
+    let mentions = scan("EUR 5.00 or $5.00 or 5 usd");
+    let confidence = mentions.iter().map(|m| m.confidence).collect::<Vec<_>>();
+
+    assert_eq!(mentions.len(), 3);
+    assert_eq!(mentions[1].money.currency.alpha3, "USD");
+    assert!(confidence[0] > confidence[1] && confidence[1] > confidence[2]);
+    assert!(confidence.iter().all(|&c| c > 0.0 && c <= 1.0));
+}
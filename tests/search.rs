@@ -0,0 +1,61 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::search;
+
+#[test]
+fn finds_exact_codes() {
+    assert_eq!(search("usd", 5)[0].currency.alpha3, "USD");
+    assert_eq!(search("840", 5)[0].currency.alpha3, "USD");
+    assert_eq!(search("usd", 5)[0].score, 1.0);
+}
+
+#[test]
+fn matches_word_prefixes() {
+    assert_eq!(search("swis fr", 5)[0].currency.alpha3, "CHF");
+    assert_eq!(search("Złoty", 5)[0].currency.alpha3, "PLN");
+}
+
+#[test]
+fn tolerates_typos_and_countries() {
+    assert_eq!(search("japnese yen", 5)[0].currency.alpha3, "JPY");
+    assert!(search("mexico", 5).iter().any(|r| r.currency.alpha3 == "MXN"));
+}
+
+#[test]
+fn limits_and_orders_results() {
+    let results = search("peso", 3);
+
+    assert_eq!(results.len(), 3);
+    assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+    assert!(results.iter().all(|r| r.currency.name.ends_with("peso")));
+    assert!(search("", 5).is_empty());
+    assert!(search("qqqqqq", 5).is_empty());
+}
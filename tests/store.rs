@@ -0,0 +1,84 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::{Date, ExchangeRate, Money, ParseDateError, Rate, RateStore, RoundingMode, alpha3};
+
+fn date(s: &str) -> Date {
+    s.parse().unwrap()
+}
+
+fn rate(base: &str, quote: &str, rate: &str) -> ExchangeRate {
+    ExchangeRate::new(alpha3(base).unwrap(), alpha3(quote).unwrap(), rate.parse().unwrap())
+}
+
+#[test]
+fn parse_dates() {
+    assert_eq!(date("2024-02-29"), Date::new(2024, 2, 29).unwrap());
+    assert_eq!(date("2024-02-29").to_string(), "2024-02-29");
+    assert_eq!("2023-02-29".parse::<Date>(), Err(ParseDateError));
+    assert_eq!("2023-1-01".parse::<Date>(), Err(ParseDateError));
+    assert!(date("2023-12-31") < date("2024-01-01"));
+}
+
+#[test]
+fn latest_rate_on_or_before_date() {
+    let (eur, usd) = (alpha3("EUR").unwrap(), alpha3("USD").unwrap());
+    let mut store = RateStore::new();
+    store.extend(vec![
+        (date("2024-01-02"), rate("EUR", "USD", "1.10")),
+        (date("2024-01-05"), rate("EUR", "USD", "1.20")),
+    ]);
+
+    assert_eq!(store.len(), 2);
+    assert!(store.rate(eur, usd, date("2024-01-01")).is_none());
+    assert_eq!(store.rate(eur, usd, date("2024-01-04")).unwrap().rate, "1.1".parse::<Rate>().unwrap());
+    assert_eq!(store.rate(eur, usd, date("2024-01-05")).unwrap().rate, "1.2".parse::<Rate>().unwrap());
+
+    let inverse = store.rate(usd, eur, date("2024-02-01")).unwrap();
+    assert_eq!(inverse.rate, Rate::new(5, 6).unwrap());
+
+    let usd_amount = store.convert(Money::new(1_000, eur), usd, date("2024-01-03"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(usd_amount.amount, 1_100);
+}
+
+#[test]
+fn falls_back_to_cross_rates() {
+    let (gbp, jpy) = (alpha3("GBP").unwrap(), alpha3("JPY").unwrap());
+    let mut store = RateStore::new();
+    store.insert(date("2024-01-02"), rate("EUR", "GBP", "0.8"));
+    store.insert(date("2024-01-02"), rate("EUR", "JPY", "160"));
+
+    let cross = store.rate(gbp, jpy, date("2024-01-03")).unwrap();
+    assert_eq!((cross.base.alpha3, cross.quote.alpha3), ("GBP", "JPY"));
+    assert_eq!(cross.rate, Rate::new(200, 1).unwrap());
+
+    store.set_pivots(&[alpha3("USD").unwrap()]);
+    assert!(store.rate(gbp, jpy, date("2024-01-03")).is_none());
+}
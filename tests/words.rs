@@ -0,0 +1,84 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 4217?
+//
+// | ISO 4217 is a standard published by the International Organization for
+// | Standardization, which delineates currency designators, country codes
+// | (alpha and numeric), and references to minor units in three tables.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_4217)
+//
+// Originally by taiyaeix on GitHub.
+
+#![cfg(feature = "alloc")]
+
+extern crate iso4217;
+
+use iso4217::{Language, Money, alpha3, amount_in_words};
+
+fn words(amount: i64, code: &str, lang: Language) -> String {
+    amount_in_words(Money::new(amount, alpha3(code).unwrap()), lang)
+}
+
+#[test]
+fn english() {
+    assert_eq!(words(123_456, "USD", Language::English), "One thousand two hundred thirty-four US dollars and 56 cents");
+    assert_eq!(words(101, "GBP", Language::English), "One pound sterling and 1 penny");
+    assert_eq!(words(56, "EUR", Language::English), "Zero euros and 56 cents");
+    assert_eq!(words(-2_000_000_500, "USD", Language::English), "Minus twenty million five US dollars");
+    assert_eq!(words(1_200, "JPY", Language::English), "One thousand two hundred Japanese yen");
+    assert_eq!(words(i64::MAX, "JPY", Language::English).split(' ').next(), Some("Nine"));
+}
+
+#[test]
+fn french() {
+    assert_eq!(words(123_456, "EUR", Language::French), "Mille deux cent trente-quatre euros et 56 centimes");
+    assert_eq!(words(8_000, "EUR", Language::French), "Quatre-vingts euros");
+    assert_eq!(words(8_100, "EUR", Language::French), "Quatre-vingt-un euros");
+    assert_eq!(words(7_100, "CHF", Language::French), "Soixante et onze francs suisses");
+    assert_eq!(words(20_000, "EUR", Language::French), "Deux cents euros");
+    assert_eq!(words(2_100, "GBP", Language::French), "Vingt et une livres sterling");
+    assert_eq!(words(100_000_000, "EUR", Language::French), "Un million d'euros");
+    assert_eq!(words(9_999, "USD", Language::French), "Quatre-vingt-dix-neuf dollars américains et 99 cents");
+}
+
+#[test]
+fn german() {
+    assert_eq!(words(123_456, "EUR", Language::German), "Eintausendzweihundertvierunddreißig Euro und 56 Cent");
+    assert_eq!(words(100, "INR", Language::German), "Eine Indische Rupie");
+    assert_eq!(words(300_000_100, "CHF", Language::German), "Drei Millionen ein Schweizer Franken");
+    assert_eq!(words(1_700, "JPY", Language::German), "Eintausendsiebenhundert Yen");
+}
+
+#[test]
+fn spanish() {
+    assert_eq!(words(123_456, "MXN", Language::Spanish), "Mil doscientos treinta y cuatro pesos mexicanos con 56 centavos");
+    assert_eq!(words(2_100, "USD", Language::Spanish), "Veintiún dólares estadounidenses");
+    assert_eq!(words(2_100, "GBP", Language::Spanish), "Veintiuna libras esterlinas");
+    assert_eq!(words(50_000, "GBP", Language::Spanish), "Quinientas libras esterlinas");
+    assert_eq!(words(100_000_000, "EUR", Language::Spanish), "Un millón de euros");
+    assert_eq!(words(100_000_000_000, "EUR", Language::Spanish), "Mil millones de euros");
+    assert_eq!(words(10_000, "EUR", Language::Spanish), "Cien euros");
+}
+
+#[test]
+fn fallback_names_and_fractions() {
+    assert_eq!(words(123_456, "ALL", Language::English), "One thousand two hundred thirty-four Albanian lek and 56/100");
+    assert_eq!(words(1_005, "KWD", Language::German), "Ein Kuwaiti dinar und 005/1000");
+    assert_eq!(Language::from_tag("es-MX"), Some(Language::Spanish));
+    assert_eq!(Language::from_tag("it"), None);
+}